@@ -0,0 +1,51 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// generates the day module declarations and the DAYS registry from every
+// src/days/day_NN.rs, so adding a day is just adding its file
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let days_dir = Path::new(&manifest_dir).join("src").join("days");
+
+    println!("cargo:rerun-if-changed={}", days_dir.display());
+
+    let mut days: Vec<u8> = fs::read_dir(&days_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let number = name.strip_prefix("day_")?.strip_suffix(".rs")?;
+
+            match number.parse() {
+                Ok(day) if number.len() == 2 && (1..=25).contains(&day) => Some(day),
+                _ => {
+                    println!("cargo:warning=ignoring src/days/{}, day files are named day_01.rs to day_25.rs", name);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    days.sort_unstable();
+
+    let mut generated = String::new();
+
+    // the generated file is include!d from src/days/mod.rs, so each module
+    // needs an absolute #[path] to be found
+    for day in &days {
+        let path = days_dir.join(format!("day_{:02}.rs", day));
+
+        generated.push_str(&format!("#[path = {:?}]\npub mod day_{:02};\n", path.display().to_string(), day));
+    }
+
+    generated.push_str("\npub const DAYS: &[(u8, &dyn DynSolution)] = &[\n");
+
+    for day in &days {
+        generated.push_str(&format!("    ({}, &day_{:02}::Day{:02}),\n", day, day, day));
+    }
+
+    generated.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("days.rs"), generated).unwrap();
+}
@@ -0,0 +1,137 @@
+use std::fs;
+use std::io;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputKind};
+use crate::solution::Answer;
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Mismatch { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "ok",
+            Verdict::Mismatch { .. } => "MISMATCH",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+// expected answers live in <input dir>/<day>/answers.txt, one per line as
+// `<input> <part> <answer>`, e.g. `test 1 3`. blank lines and lines
+// starting with `#` are ignored
+#[derive(Debug, Default, PartialEq)]
+pub struct ExpectedAnswers {
+    entries: Vec<(String, u8, String)>,
+}
+
+impl ExpectedAnswers {
+    pub fn get(&self, input: &InputKind, part: u8) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(i, p, _)| *i == input.label() && *p == part)
+            .map(|(_, _, answer)| answer.as_str())
+    }
+
+    pub fn check(&self, input: &InputKind, part: u8, answer: &Answer) -> Verdict {
+        match self.get(input, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Mismatch { expected: expected.to_string() },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+pub fn load_expected_answers(day: u8) -> Result<ExpectedAnswers> {
+    let path = input::day_dir(day).join("answers.txt");
+
+    match fs::read_to_string(&path) {
+        Ok(text) => parse_answers(&text)
+            .map_err(|e| Error::parse(format!("{}: {}", path.display(), e))),
+        // a day without an answers file just has no known answers yet
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(ExpectedAnswers::default()),
+        Err(e) => Err(Error::io(path, e)),
+    }
+}
+
+fn parse_answers(text: &str) -> std::result::Result<ExpectedAnswers, String> {
+    let mut entries = vec![];
+
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.splitn(3, char::is_whitespace);
+
+        let (Some(input), Some(part), Some(answer)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(format!("line {}: expected `<input> <part> <answer>`", n + 1));
+        };
+
+        let part: u8 = part
+            .parse()
+            .map_err(|_| format!("line {}: part must be a number, got '{}'", n + 1, part))?;
+
+        entries.push((input.to_string(), part, answer.trim().to_string()));
+    }
+
+    Ok(ExpectedAnswers { entries })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Verdict,
+        parse_answers,
+    };
+    use crate::input::InputKind;
+    use crate::solution::Answer;
+
+    #[test]
+    fn parses_answers_file() {
+        let text = "# day 5
+test 1 3
+test 2 14
+test2 1 3
+
+real 1 789";
+
+        let answers = parse_answers(text).unwrap();
+
+        assert_eq!(answers.get(&InputKind::Example(1), 1), Some("3"));
+        assert_eq!(answers.get(&InputKind::Example(1), 2), Some("14"));
+        assert_eq!(answers.get(&InputKind::Example(2), 1), Some("3"));
+        assert_eq!(answers.get(&InputKind::Example(2), 2), None);
+        assert_eq!(answers.get(&InputKind::Real, 1), Some("789"));
+        assert_eq!(answers.get(&InputKind::Real, 2), None);
+    }
+
+    #[test]
+    fn checks_answer_against_expected() {
+        let answers = parse_answers("test 1 3").unwrap();
+
+        assert_eq!(answers.check(&InputKind::Example(1), 1, &Answer::Number(3)), Verdict::Correct);
+        assert_eq!(
+            answers.check(&InputKind::Example(1), 1, &Answer::Number(4)),
+            Verdict::Mismatch { expected: String::from("3") },
+        );
+        assert_eq!(answers.check(&InputKind::Example(1), 2, &Answer::Number(3)), Verdict::Unknown);
+    }
+
+    #[test]
+    fn rejects_line_without_answer() {
+        assert!(parse_answers("test 1").is_err());
+    }
+
+    #[test]
+    fn rejects_non_numeric_part() {
+        assert!(parse_answers("test one 3").is_err());
+    }
+}
@@ -0,0 +1,531 @@
+use aoc2025::bench::DEFAULT_ITERATIONS;
+use aoc2025::config::Config;
+use aoc2025::error::{Error, Result};
+use aoc2025::history::DEFAULT_REGRESSION_THRESHOLD;
+use aoc2025::input::{InputKind, InputSelector};
+
+pub const USAGE: &str = "Usage:
+  aoc2025 <day> <optional: 1 / 2 / both> <optional: input>
+  aoc2025 all <optional: inputs...>
+  aoc2025 verify <optional: inputs...>
+  aoc2025 bench <day> <1 / 2> <optional: input> <optional: --iterations N> <optional: --save-baseline>
+  aoc2025 bench <optional: day> <optional: 1 / 2 / both> <optional: input> --compare <optional: --threshold PERCENT> <optional: --save-baseline>
+  aoc2025 fetch <day>
+  aoc2025 submit <day> <1 / 2>
+  aoc2025 new <day>
+  aoc2025 --list
+
+Inputs:
+  real      input/<day>/input.txt, the default
+  test      input/<day>/test.txt
+  test:N    input/<day>/testN.txt
+  test:all  every example for the day
+
+Options:
+  --format <text / json>  output format, json prints one object per line
+  --input <path / ->      read the puzzle input from a file, or stdin with `-`
+
+Set AOC_INPUT_DIR to read inputs from somewhere other than ./input
+Set AOC_SESSION (or write it to .session), AOC_BASE_URL and AOC_CONTACT for fetch and submit
+Defaults for these and the options above can be set in aoc.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    // no part runs both parts on a single parse
+    Run { day: u8, part: Option<u8>, input: InputSelector },
+    All { inputs: Vec<InputSelector> },
+    Verify { inputs: Vec<InputSelector> },
+    Bench {
+        // when no day is given every day is benchmarked, and a day without a
+        // part has both of its parts benchmarked. both only with --compare
+        target: Option<(u8, Option<u8>)>,
+        input: InputSelector,
+        iterations: u32,
+        compare: bool,
+        threshold: f64,
+        // record the new medians as the baseline later compares measure against
+        save_baseline: bool,
+    },
+    Fetch { day: u8 },
+    // solves the real input and sends the answer
+    Submit { day: u8, part: u8 },
+    // generates a new day module
+    New { day: u8 },
+    // shows every registered day and its inputs
+    List,
+}
+
+// argument errors are plain messages until they reach parse_args
+type ArgResult<T> = std::result::Result<T, String>;
+
+// flags that aren't given fall back to the config, then to the built in defaults
+pub fn parse_args(args: &[String], config: &Config) -> Result<Args> {
+    parse(args, config).map_err(Error::BadArguments)
+}
+
+fn parse(args: &[String], config: &Config) -> ArgResult<Args> {
+    let mut args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    let format = match take_flag_value(&mut args, "--format")?.or(config.format.as_deref()) {
+        Some("text") | None => Format::Text,
+        Some("json") => Format::Json,
+        Some(other) => return Err(format!("unknown format '{}', expected text or json", other)),
+    };
+
+    let iterations = match take_flag_value(&mut args, "--iterations")? {
+        Some(n) => n
+            .parse()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| format!("--iterations must be a positive number, got '{}'", n))?,
+        None => config.iterations.unwrap_or(DEFAULT_ITERATIONS),
+    };
+
+    let threshold = match take_flag_value(&mut args, "--threshold")? {
+        Some(t) => t
+            .parse()
+            .ok()
+            .filter(|&t: &f64| t >= 0.0)
+            .ok_or_else(|| format!("--threshold must be a non-negative percentage, got '{}'", t))?,
+        None => config.threshold.unwrap_or(DEFAULT_REGRESSION_THRESHOLD),
+    };
+
+    let compare = take_flag(&mut args, "--compare");
+    let save_baseline = take_flag(&mut args, "--save-baseline");
+
+    let input = take_flag_value(&mut args, "--input")?.map(InputKind::from_arg);
+
+    if take_flag(&mut args, "--list") {
+        if !args.is_empty() || input.is_some() {
+            return Err(String::from("--list can't be combined with a command"));
+        }
+
+        return Ok(Args { command: Command::List, format });
+    }
+
+    let mut command = parse_command(&args, iterations, compare, threshold, save_baseline)?;
+
+    if let Some(path) = input {
+        override_input(&mut command, path)?;
+    }
+
+    Ok(Args { command, format })
+}
+
+// swaps the default input for one given with --input
+fn override_input(command: &mut Command, path: InputKind) -> ArgResult<()> {
+    match command {
+        Command::Run { input, .. }
+        | Command::Bench { target: Some((_, Some(_))), input, .. } if *input == InputSelector::One(InputKind::Real) => {
+            *input = InputSelector::One(path);
+            Ok(())
+        }
+        Command::Run { .. } | Command::Bench { target: Some((_, Some(_))), .. } => {
+            Err(String::from("--input can't be combined with another input"))
+        }
+        _ => Err(String::from("--input can only be used with a single day and part")),
+    }
+}
+
+fn parse_command(args: &[&str], iterations: u32, compare: bool, threshold: f64, save_baseline: bool) -> ArgResult<Command> {
+    match args {
+        ["bench", rest @ ..] => parse_bench(rest, iterations, compare, threshold, save_baseline),
+        ["all", rest @ ..] => Ok(Command::All { inputs: parse_input_selectors(rest)? }),
+        ["verify", rest @ ..] => Ok(Command::Verify { inputs: parse_input_selectors(rest)? }),
+        ["fetch", day] => Ok(Command::Fetch { day: parse_day(day)? }),
+        ["fetch", ..] => Err(String::from("fetch takes a single day")),
+        ["submit", day, part] => {
+            let (day, part) = parse_day_and_part(day, part)?;
+
+            Ok(Command::Submit { day, part })
+        }
+        ["submit", ..] => Err(String::from("submit takes a day and a part")),
+        ["new", day] => Ok(Command::New { day: parse_day(day)? }),
+        ["new", ..] => Err(String::from("new takes a single day")),
+        [day] => parse_run(day, "both", "real"),
+        // the part can be left out, e.g. `5 test`
+        [day, input] if !is_part(input) => parse_run(day, "both", input),
+        [day, part] => parse_run(day, part, "real"),
+        [day, part, input] => parse_run(day, part, input),
+        _ => Err(String::from("wrong number of arguments")),
+    }
+}
+
+fn parse_run(day: &str, part: &str, input: &str) -> ArgResult<Command> {
+    let input = parse_input_selector(input)?;
+
+    if part == "both" {
+        return Ok(Command::Run { day: parse_day(day)?, part: None, input });
+    }
+
+    let (day, part) = parse_day_and_part(day, part)?;
+
+    Ok(Command::Run { day, part: Some(part), input })
+}
+
+fn is_part(arg: &str) -> bool {
+    arg == "both" || arg.parse::<u8>().is_ok()
+}
+
+fn parse_day(day: &str) -> ArgResult<u8> {
+    day.parse().map_err(|_| format!("day must be a number, got '{}'", day))
+}
+
+fn parse_day_and_part(day: &str, part: &str) -> ArgResult<(u8, u8)> {
+    let day = parse_day(day)?;
+    let part: u8 = part.parse().map_err(|_| format!("part must be a number, got '{}'", part))?;
+
+    if part != 1 && part != 2 {
+        return Err(format!("part must be 1 or 2, got {}", part));
+    }
+
+    Ok((day, part))
+}
+
+fn parse_bench(rest: &[&str], iterations: u32, compare: bool, threshold: f64, save_baseline: bool) -> ArgResult<Command> {
+    // like run, the part can be left out, e.g. `bench 5 --compare`
+    let (target, input) = match rest {
+        [] => (None, "real"),
+        [input] if !is_part(input) => (None, *input),
+        [day] => (Some(parse_bench_target(day, "both")?), "real"),
+        [day, input] if !is_part(input) => (Some(parse_bench_target(day, "both")?), *input),
+        [day, part] => (Some(parse_bench_target(day, part)?), "real"),
+        [day, part, input] => (Some(parse_bench_target(day, part)?), *input),
+        _ => return Err(String::from("wrong number of arguments to bench")),
+    };
+
+    let input = parse_input_selector(input)?;
+
+    if !matches!(target, Some((_, Some(_)))) && !compare {
+        return Err(String::from("bench needs a day and part unless --compare is given"));
+    }
+
+    if !compare && input == InputSelector::AllExamples {
+        return Err(String::from("bench needs a single input unless --compare is given"));
+    }
+
+    Ok(Command::Bench { target, input, iterations, compare, threshold, save_baseline })
+}
+
+fn parse_bench_target(day: &str, part: &str) -> ArgResult<(u8, Option<u8>)> {
+    if part == "both" {
+        return Ok((parse_day(day)?, None));
+    }
+
+    let (day, part) = parse_day_and_part(day, part)?;
+
+    Ok((day, Some(part)))
+}
+
+// removes `flag` from the args, returning whether it was present
+fn take_flag(args: &mut Vec<&str>, flag: &str) -> bool {
+    let len = args.len();
+
+    args.retain(|&a| a != flag);
+
+    args.len() != len
+}
+
+// removes `flag` and the value following it from the args, if present
+fn take_flag_value<'a>(args: &mut Vec<&'a str>, flag: &str) -> ArgResult<Option<&'a str>> {
+    let Some(i) = args.iter().position(|&a| a == flag) else {
+        return Ok(None);
+    };
+
+    if i + 1 >= args.len() {
+        return Err(format!("{} requires a value", flag));
+    }
+
+    let value = args.remove(i + 1);
+    args.remove(i);
+
+    Ok(Some(value))
+}
+
+fn parse_input_selectors(rest: &[&str]) -> ArgResult<Vec<InputSelector>> {
+    let mut inputs = vec![];
+
+    for arg in rest {
+        let selector = parse_input_selector(arg)?;
+
+        if !inputs.contains(&selector) {
+            inputs.push(selector);
+        }
+    }
+
+    // real input is the default when no inputs are given
+    if inputs.is_empty() {
+        inputs.push(InputSelector::One(InputKind::Real));
+    }
+
+    Ok(inputs)
+}
+
+fn parse_input_selector(arg: &str) -> ArgResult<InputSelector> {
+    InputSelector::from_arg(arg)
+        .ok_or_else(|| format!("unknown input '{}', expected real, test, test:N or test:all", arg))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{
+        Command,
+        Format,
+        parse_args,
+    };
+    use aoc2025::config::Config;
+    use aoc2025::input::{InputKind, InputSelector};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    fn command(s: &str) -> Result<Command, String> {
+        parse_args(&args(s), &Config::default())
+            .map(|a| a.command)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn parses_single_run() {
+        assert_eq!(
+            command("4 2 test"),
+            Ok(Command::Run { day: 4, part: Some(2), input: InputSelector::One(InputKind::Example(1)) }),
+        );
+    }
+
+    #[test]
+    fn parses_all_with_default_input() {
+        assert_eq!(
+            command("all"),
+            Ok(Command::All { inputs: vec![InputSelector::One(InputKind::Real)] }),
+        );
+    }
+
+    #[test]
+    fn parses_all_with_both_inputs() {
+        assert_eq!(
+            command("all test real"),
+            Ok(Command::All { inputs: vec![InputSelector::One(InputKind::Example(1)), InputSelector::One(InputKind::Real)] }),
+        );
+    }
+
+    #[test]
+    fn parses_verify_with_test_input() {
+        assert_eq!(
+            command("verify test"),
+            Ok(Command::Verify { inputs: vec![InputSelector::One(InputKind::Example(1))] }),
+        );
+    }
+
+    #[test]
+    fn parses_bench_with_default_iterations() {
+        assert_eq!(
+            command("bench 7 2"),
+            Ok(Command::Bench {
+                target: Some((7, Some(2))),
+                input: InputSelector::One(InputKind::Real),
+                iterations: 100,
+                compare: false,
+                threshold: 10.0,
+                save_baseline: false,
+            }),
+        );
+    }
+
+    #[test]
+    fn parses_bench_with_iterations_flag() {
+        assert_eq!(
+            command("bench 7 1 --iterations 500 test"),
+            Ok(Command::Bench {
+                target: Some((7, Some(1))),
+                input: InputSelector::One(InputKind::Example(1)),
+                iterations: 500,
+                compare: false,
+                threshold: 10.0,
+                save_baseline: false,
+            }),
+        );
+    }
+
+    #[test]
+    fn parses_chosen_and_all_examples() {
+        assert_eq!(
+            command("3 1 test:2"),
+            Ok(Command::Run { day: 3, part: Some(1), input: InputSelector::One(InputKind::Example(2)) }),
+        );
+        assert_eq!(
+            command("verify test:all real"),
+            Ok(Command::Verify {
+                inputs: vec![InputSelector::AllExamples, InputSelector::One(InputKind::Real)],
+            }),
+        );
+    }
+
+    #[test]
+    fn rejects_bench_of_all_examples_without_compare() {
+        assert!(command("bench 3 1 test:all").is_err());
+        assert!(command("bench 3 1 test:all --compare").is_ok());
+    }
+
+    #[test]
+    fn parses_bench_compare_for_every_day() {
+        assert_eq!(
+            command("bench --compare --threshold 5"),
+            Ok(Command::Bench {
+                target: None,
+                input: InputSelector::One(InputKind::Real),
+                iterations: 100,
+                compare: true,
+                threshold: 5.0,
+                save_baseline: false,
+            }),
+        );
+    }
+
+    #[test]
+    fn parses_save_baseline_flag() {
+        assert!(matches!(command("bench 7 2 --save-baseline"), Ok(Command::Bench { compare: false, save_baseline: true, .. })));
+        assert!(matches!(command("bench --compare --save-baseline"), Ok(Command::Bench { compare: true, save_baseline: true, .. })));
+    }
+
+    #[test]
+    fn parses_bench_compare_for_both_parts_of_a_day() {
+        assert!(matches!(command("bench 7 --compare"), Ok(Command::Bench { target: Some((7, None)), .. })));
+        assert!(matches!(
+            command("bench 7 test:all --compare"),
+            Ok(Command::Bench { target: Some((7, None)), input: InputSelector::AllExamples, .. })));
+        assert!(command("bench 7").is_err());
+    }
+
+    #[test]
+    fn rejects_bench_without_target_or_compare() {
+        assert!(command("bench").is_err());
+    }
+
+    #[test]
+    fn rejects_flag_without_value() {
+        assert!(command("bench 7 1 --iterations").is_err());
+    }
+
+    #[test]
+    fn parses_format_flag() {
+        assert_eq!(parse_args(&args("all"), &Config::default()).unwrap().format, Format::Text);
+        assert_eq!(parse_args(&args("all --format json"), &Config::default()).unwrap().format, Format::Json);
+        assert!(parse_args(&args("all --format xml"), &Config::default()).is_err());
+    }
+
+    #[test]
+    fn falls_back_to_config_unless_flag_given() {
+        let config = Config {
+            format: Some(String::from("json")),
+            iterations: Some(20),
+            threshold: Some(2.5),
+            ..Config::default()
+        };
+
+        let from_config = parse_args(&args("bench 7 2"), &config).unwrap();
+
+        assert_eq!(from_config.format, Format::Json);
+        assert_eq!(
+            from_config.command,
+            Command::Bench {
+                target: Some((7, Some(2))),
+                input: InputSelector::One(InputKind::Real),
+                iterations: 20,
+                compare: false,
+                threshold: 2.5,
+                save_baseline: false,
+            },
+        );
+
+        let from_flags = parse_args(&args("bench 7 2 --format text --iterations 300 --threshold 5"), &config).unwrap();
+
+        assert_eq!(from_flags.format, Format::Text);
+        assert!(matches!(from_flags.command, Command::Bench { iterations: 300, threshold: 5.0, .. }));
+    }
+
+    #[test]
+    fn parses_input_path() {
+        assert_eq!(
+            command("6 2 --input stress.txt"),
+            Ok(Command::Run { day: 6, part: Some(2), input: InputSelector::One(InputKind::Path(PathBuf::from("stress.txt"))) }),
+        );
+    }
+
+    #[test]
+    fn parses_stdin_input() {
+        assert_eq!(
+            command("6 2 --input -"),
+            Ok(Command::Run { day: 6, part: Some(2), input: InputSelector::One(InputKind::Stdin) }),
+        );
+    }
+
+    #[test]
+    fn rejects_input_path_for_multiple_days() {
+        assert!(command("all --input stress.txt").is_err());
+        assert!(command("6 2 test --input stress.txt").is_err());
+    }
+
+    #[test]
+    fn parses_fetch() {
+        assert_eq!(command("fetch 8"), Ok(Command::Fetch { day: 8 }));
+        assert!(command("fetch").is_err());
+        assert!(command("fetch 8 9").is_err());
+    }
+
+    #[test]
+    fn parses_submit() {
+        assert_eq!(command("submit 8 2"), Ok(Command::Submit { day: 8, part: 2 }));
+        assert!(command("submit 8").is_err());
+        assert!(command("submit 8 3").is_err());
+    }
+
+    #[test]
+    fn parses_new() {
+        assert_eq!(command("new 12"), Ok(Command::New { day: 12 }));
+        assert!(command("new").is_err());
+    }
+
+    #[test]
+    fn parses_list() {
+        assert_eq!(command("--list"), Ok(Command::List));
+        assert!(command("--list all").is_err());
+    }
+
+    #[test]
+    fn parses_run_of_both_parts() {
+        let both = |input| Ok(Command::Run { day: 4, part: None, input: InputSelector::One(input) });
+
+        assert_eq!(command("4"), both(InputKind::Real));
+        assert_eq!(command("4 both"), both(InputKind::Real));
+        assert_eq!(command("4 both test"), both(InputKind::Example(1)));
+        assert_eq!(command("4 test:2"), both(InputKind::Example(2)));
+    }
+
+    #[test]
+    fn rejects_wrong_argument_count() {
+        assert!(command("").is_err());
+        assert!(command("4 1 test extra").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_part() {
+        assert!(command("4 3").is_err());
+        assert!(command("4 neither").is_err());
+    }
+}
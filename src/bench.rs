@@ -0,0 +1,170 @@
+use std::hint::black_box;
+use std::time::Duration;
+
+use crate::error::Result;
+use crate::history::{self, History};
+use crate::input::InputKind;
+use crate::runner;
+
+pub const DEFAULT_ITERATIONS: u32 = 100;
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    pub low_outliers: usize,
+    pub high_outliers: usize,
+}
+
+pub fn warmup_iterations(iterations: u32) -> u32 {
+    (iterations / 10).max(1)
+}
+
+// a fresh benchmark next to the saved baseline
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub input: InputKind,
+    pub baseline: Option<Duration>,
+    // the git revision the baseline was saved at
+    pub baseline_revision: Option<String>,
+    pub change: Option<f64>,
+    pub median: Duration,
+    pub regression: bool,
+}
+
+// benchmarks a day and part against its saved baseline, regressing if it
+// got slower by more than `threshold` percent. the new median isn't recorded
+pub fn compare(history: &History, day: u8, part: u8, input: &InputKind, iterations: u32, threshold: f64) -> Result<Comparison> {
+    let saved = history.baseline(day, part, input);
+    let baseline = saved.map(|e| e.elapsed);
+    let stats = bench(day, part, input, iterations)?;
+
+    Ok(Comparison {
+        day,
+        part,
+        input: input.clone(),
+        baseline,
+        baseline_revision: saved.map(|e| e.revision.clone()),
+        change: baseline.map(|b| history::percent_change(b, stats.median)),
+        median: stats.median,
+        regression: baseline.is_some_and(|b| history::is_regression(b, stats.median, threshold)),
+    })
+}
+
+pub fn bench(day: u8, part: u8, kind: &InputKind, iterations: u32) -> Result<Stats> {
+    let (solution, input, _) = runner::prepare(day, kind)?;
+
+    for _ in 0..warmup_iterations(iterations) {
+        // a broken input fails here on the first run, before anything is measured
+        black_box(solution.run(black_box(&input), part).map_err(|e| e.in_input(runner::input_name(day, kind)))?);
+    }
+
+    // each sample covers both the parse and solve phases
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let timed = black_box(solution.run(black_box(&input), part)?);
+            Ok(timed.parse + timed.solve)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(compute_stats(&mut samples))
+}
+
+pub fn compute_stats(samples: &mut [Duration]) -> Stats {
+    assert!(!samples.is_empty(), "cannot compute stats without samples");
+
+    samples.sort_unstable();
+
+    let n = samples.len();
+    let nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+
+    let mean = nanos.iter().sum::<f64>() / n as f64;
+    let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+    // tukey's fences: anything more than 1.5 IQR outside the quartiles is an outlier
+    let q1 = percentile(&nanos, 0.25);
+    let q3 = percentile(&nanos, 0.75);
+    let iqr = q3 - q1;
+    let low_fence = q1 - 1.5 * iqr;
+    let high_fence = q3 + 1.5 * iqr;
+
+    Stats {
+        samples: n,
+        min: samples[0],
+        median: from_nanos(percentile(&nanos, 0.5)),
+        mean: from_nanos(mean),
+        p95: from_nanos(percentile(&nanos, 0.95)),
+        std_dev: from_nanos(variance.sqrt()),
+        low_outliers: nanos.iter().filter(|&&x| x < low_fence).count(),
+        high_outliers: nanos.iter().filter(|&&x| x > high_fence).count(),
+    }
+}
+
+// linear interpolation between the closest ranks of a sorted slice
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::compute_stats;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_single_sample() {
+        let stats = compute_stats(&mut micros(&[5]));
+
+        assert_eq!(stats.min, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn computes_stats_for_unsorted_samples() {
+        let stats = compute_stats(&mut micros(&[4, 2, 8, 6]));
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        // sqrt(((3^2) + (1^2) + (1^2) + (3^2)) / 4) = sqrt(5)
+        assert_eq!(stats.std_dev, Duration::from_nanos(2236));
+    }
+
+    #[test]
+    fn interpolates_p95() {
+        let mut samples = micros(&(1..=21).collect::<Vec<u64>>());
+
+        let stats = compute_stats(&mut samples);
+
+        assert_eq!(stats.p95, Duration::from_micros(20));
+    }
+
+    #[test]
+    fn flags_outliers() {
+        let stats = compute_stats(&mut micros(&[10, 11, 10, 12, 11, 10, 100, 1]));
+
+        assert_eq!(stats.high_outliers, 1);
+        assert_eq!(stats.low_outliers, 1);
+    }
+}
@@ -0,0 +1,259 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::error::{Error, Result};
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+// defaults for the runner, read from aoc.toml, e.g.
+//
+// input_dir = "../aoc-inputs"
+// format = "json"
+//
+// [bench]
+// iterations = 500
+// threshold = 5.0
+//
+// [fetch]
+// base_url = "http://localhost:8080"
+// contact = "you@example.com"
+// session_file = "../.aoc-session"
+//
+// paths are relative to the current directory. command line flags and
+// environment variables take precedence over all of these
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    // either "text" or "json"
+    pub format: Option<String>,
+    pub iterations: Option<u32>,
+    pub threshold: Option<f64>,
+    pub base_url: Option<String>,
+    // who to reach about the requests, sent in the User-Agent
+    pub contact: Option<String>,
+    pub session_file: Option<PathBuf>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// reads aoc.toml if there is one, making it available through `current`
+pub fn init() -> Result<&'static Config> {
+    let config = match fs::read_to_string(CONFIG_PATH) {
+        Ok(text) => parse_config(&text).map_err(|e| e.in_input(CONFIG_PATH))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+        Err(e) => return Err(Error::io(CONFIG_PATH, e)),
+    };
+
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+// the config loaded by `init`, or an empty one if it wasn't called
+pub fn current() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+}
+
+// a small subset of TOML: `[section]` headers and `key = value` lines where a
+// value is a basic string, an integer or a float. `#` starts a comment
+pub fn parse_config(text: &str) -> Result<Config> {
+    let mut config = Config::default();
+    let mut section = "";
+
+    for line in text.lines() {
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .ok_or_else(|| Error::parse_at(text, line, "expected `]` to close the section"))?
+                .trim();
+
+            if !["bench", "fetch"].contains(&name) {
+                return Err(Error::parse_at(text, line, format!("unknown section [{}], expected [bench] or [fetch]", name)));
+            }
+
+            section = name;
+            continue;
+        }
+
+        let (key, raw) = line
+            .split_once('=')
+            .ok_or_else(|| Error::parse_at(text, line, "expected `key = value`"))?;
+
+        let (key, raw) = (key.trim(), raw.trim());
+        let value = parse_value(text, raw)?;
+
+        let expected = |what: &str| Error::parse_at(text, raw, format!("{} must be {}", key, what));
+
+        match (section, key, value) {
+            ("", "input_dir", Value::String(s)) => config.input_dir = Some(PathBuf::from(s)),
+            ("", "input_dir", _) => return Err(expected("a string")),
+            ("", "format", Value::String(s)) if s == "text" || s == "json" => config.format = Some(s),
+            ("", "format", _) => return Err(expected("\"text\" or \"json\"")),
+            ("bench", "iterations", Value::Integer(n)) if n > 0 && n <= u32::MAX as i64 => {
+                config.iterations = Some(n as u32);
+            }
+            ("bench", "iterations", _) => return Err(expected("a positive integer")),
+            ("bench", "threshold", Value::Float(t)) if t >= 0.0 => config.threshold = Some(t),
+            ("bench", "threshold", Value::Integer(t)) if t >= 0 => config.threshold = Some(t as f64),
+            ("bench", "threshold", _) => return Err(expected("a non-negative number")),
+            ("fetch", "base_url", Value::String(s)) => config.base_url = Some(s),
+            ("fetch", "base_url", _) => return Err(expected("a string")),
+            ("fetch", "contact", Value::String(s)) => config.contact = Some(s),
+            ("fetch", "contact", _) => return Err(expected("a string")),
+            ("fetch", "session_file", Value::String(s)) => config.session_file = Some(PathBuf::from(s)),
+            ("fetch", "session_file", _) => return Err(expected("a string")),
+            _ if section.is_empty() => {
+                return Err(Error::parse_at(text, key, format!("unknown key '{}'", key)));
+            }
+            _ => return Err(Error::parse_at(text, key, format!("unknown key '{}' in [{}]", key, section))),
+        }
+    }
+
+    Ok(config)
+}
+
+// drops a `#` comment, unless the `#` is inside a string
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn parse_value(text: &str, raw: &str) -> Result<Value> {
+    if let Some(quoted) = raw.strip_prefix('"') {
+        let inner = quoted
+            .strip_suffix('"')
+            .ok_or_else(|| Error::parse_at(text, raw, "unterminated string"))?;
+
+        return unescape(inner)
+            .map(Value::String)
+            .ok_or_else(|| Error::parse_at(text, raw, "invalid escape in string"));
+    }
+
+    // TOML allows underscores between digits, e.g. 1_000
+    let number = raw.replace('_', "");
+
+    if let Ok(n) = number.parse() {
+        return Ok(Value::Integer(n));
+    }
+
+    if let Ok(f) = number.parse() {
+        return Ok(Value::Float(f));
+    }
+
+    Err(Error::parse_at(text, raw, format!("expected a string or a number, got '{}'", raw)))
+}
+
+fn unescape(s: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        result.push(match chars.next()? {
+            '"' => '"',
+            '\\' => '\\',
+            'n' => '\n',
+            't' => '\t',
+            _ => return None,
+        });
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{
+        Config,
+        parse_config,
+    };
+    use crate::error::{Error, Location};
+
+    #[test]
+    fn parses_every_setting() {
+        let text = "# runner defaults
+input_dir = \"../inputs\"
+format = \"json\" # one object per line
+
+[bench]
+iterations = 1_000
+threshold = 2.5
+
+[fetch]
+base_url = \"http://localhost:8080\"
+contact = \"someone@example.com\"
+session_file = \"secrets/session#1\"
+";
+
+        assert_eq!(
+            parse_config(text).unwrap(),
+            Config {
+                input_dir: Some(PathBuf::from("../inputs")),
+                format: Some(String::from("json")),
+                iterations: Some(1000),
+                threshold: Some(2.5),
+                base_url: Some(String::from("http://localhost:8080")),
+                contact: Some(String::from("someone@example.com")),
+                session_file: Some(PathBuf::from("secrets/session#1")),
+            },
+        );
+    }
+
+    #[test]
+    fn parses_empty_config() {
+        assert_eq!(parse_config("\n# nothing here\n").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn accepts_integer_threshold() {
+        assert_eq!(parse_config("[bench]\nthreshold = 5").unwrap().threshold, Some(5.0));
+    }
+
+    #[test]
+    fn points_at_bad_value() {
+        let Err(Error::Parse(e)) = parse_config("[bench]\niterations = \"many\"") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(e.message, "iterations must be a positive integer");
+        assert_eq!(e.location, Some(Location { line: 2, column: 14, text: String::from("iterations = \"many\""), width: 6 }));
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_sections() {
+        assert!(parse_config("iterations = 5").is_err());
+        assert!(parse_config("[benchmark]").is_err());
+        assert!(parse_config("format = \"xml\"").is_err());
+        assert!(parse_config("input_dir = \"unterminated").is_err());
+    }
+}
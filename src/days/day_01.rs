@@ -1,17 +1,43 @@
-pub fn day_01(input: &str, part: u8) {
-    let mut dial = 50;
-    let mut zero_hits = 0;
+use crate::error::Result;
+use crate::scanner::Scanner;
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+pub type Rotation = (i8, u32);
+
+impl Solution for Day01 {
+    type Input = Vec<Rotation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_rotations(input)
+    }
+
+    fn part_one(&self, rotations: &Self::Input) -> Result<Answer> {
+        // part 1 solution only increments the counter if it lands on 0
+        Ok(count_zero_hits(rotations, false).into())
+    }
 
-    for line in input.lines() {
-        let (direction_str, distance_str) = line.split_at(1);
-        let direction: i8 = match direction_str {
-            "L" => -1,
-            "R" => 1,
-            other => panic!("unsupported direction: {}", other),
-        };
+    fn part_two(&self, rotations: &Self::Input) -> Result<Answer> {
+        // part 2 solution increments counter for any time it passes 0
+        Ok(count_zero_hits(rotations, true).into())
+    }
+}
 
-        let mut distance: u32 = distance_str.trim().parse().expect("unable to parse distance");
+pub fn parse_rotations(input: &str) -> Result<Vec<Rotation>> {
+    Scanner::new(input).each_line(|line| {
+        let direction = line.one_of(&[("L", -1), ("R", 1)])?;
+        let distance = line.unsigned()?;
+
+        Ok((direction, distance))
+    })
+}
 
+pub fn count_zero_hits(rotations: &[Rotation], count_passes: bool) -> u32 {
+    let mut dial = 50;
+    let mut zero_hits = 0;
+
+    for &(direction, mut distance) in rotations {
         while distance > 0 {
             dial += direction;
             distance -= 1;
@@ -22,17 +48,15 @@ pub fn day_01(input: &str, part: u8) {
                 dial -= 100;
             }
 
-            // part 2 solution increments counter for any time it passes 0
-            if part == 2 && dial == 0 {
+            if count_passes && dial == 0 {
                 zero_hits += 1;
             }
         }
 
-        // part 1 solution only increments the counter if it lands on 0
-        if part == 1 && dial == 0 {
+        if !count_passes && dial == 0 {
             zero_hits += 1;
         }
     }
 
-    println!("zero hits: {}", zero_hits);
+    zero_hits
 }
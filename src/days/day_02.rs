@@ -1,61 +1,87 @@
-pub fn day_02(input: &str, part: u8) {
-    let repeat_check_fn = if part == 1 {
-        is_repeated_pattern
-    } else {
-        contains_repeated_pattern
-    };
-
-    let ranges = input.split(',');
+use std::ops::RangeInclusive;
 
-    let mut sum = 0;
+use crate::digits::Digits;
+use crate::error::Result;
+use crate::scanner::Scanner;
+use crate::solution::{Answer, Solution};
 
-    for range in ranges {
-        let mut bounds = range
-            .split('-')
-            .map(|x| x.parse::<u64>().expect("could not parse range part"));
+pub struct Day02;
 
-        let start = bounds.next().expect("could not get start from range");
-        let end = bounds.next().expect("could not get end from range");
+impl Solution for Day02 {
+    type Input = Vec<RangeInclusive<u64>>;
 
-        let repeated = (start..=end)
-            .filter(|n| repeat_check_fn(n));
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_ranges(input)
+    }
 
-        sum += repeated.sum::<u64>();
+    fn part_one(&self, ranges: &Self::Input) -> Result<Answer> {
+        Ok(sum_invalid_ids(ranges, is_repeated_pattern).into())
     }
 
-    println!("sum of invalid ids: {}", sum);
+    fn part_two(&self, ranges: &Self::Input) -> Result<Answer> {
+        Ok(sum_invalid_ids(ranges, contains_repeated_pattern).into())
+    }
 }
 
-fn is_repeated_pattern(n: &u64) -> bool {
-    let s = n.to_string();
-    let len = s.len();
+pub fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<u64>>> {
+    let mut scanner = Scanner::new(input);
+    let ranges = scanner.separated(",", |s| s.unsigned_range())?;
 
-    // length must be even for the string to consist solely of a repeated pattern
-    if len % 2 != 0 {
-        return false;
-    }
+    scanner.end()?;
 
-    let (part_a, part_b) = s.split_at(len / 2);
+    Ok(ranges)
+}
 
-    return part_a == part_b;
+pub fn sum_invalid_ids(ranges: &[RangeInclusive<u64>], repeat_check_fn: fn(&u64) -> bool) -> u64 {
+    ranges
+        .iter()
+        .map(|range| {
+            range
+                .clone()
+                .filter(repeat_check_fn)
+                .sum::<u64>()
+        })
+        .sum()
 }
 
-fn contains_repeated_pattern(n: &u64) -> bool {
-    let s = n.to_string();
-    let len = s.len();
+pub fn is_repeated_pattern(n: &u64) -> bool {
+    n.is_repeated_block(2, 10)
+}
 
-    let midpoint = len / 2;
+pub fn contains_repeated_pattern(n: &u64) -> bool {
+    // any block repeated at least twice
+    (2..=n.digit_count(10)).any(|times| n.is_repeated_block(times, 10))
+}
 
-    return (1..=midpoint)
-        .filter(|l| len % l == 0)
-        .any(|pattern_len| {
-            // split the string into chunks of the pattern length
-            let mut chunks = s
-                .as_bytes()
-                .chunks(pattern_len);
+#[cfg(test)]
+mod test {
+    use super::{
+        contains_repeated_pattern,
+        is_repeated_pattern,
+        parse_ranges,
+    };
+
+    #[test]
+    fn parses_input_correctly() {
+        let result = parse_ranges("11-22,95-115").unwrap();
+
+        assert_eq!(result, vec![11..=22, 95..=115]);
+    }
 
-            // all chunks are equal to the 1st then the pattern repeats
-            let first = chunks.next().unwrap();
-            return chunks.all(|c| c == first);
-        });
+    #[test]
+    fn finds_pattern_repeated_twice() {
+        assert!(is_repeated_pattern(&55));
+        assert!(is_repeated_pattern(&123123));
+        assert!(!is_repeated_pattern(&111));
+        assert!(!is_repeated_pattern(&1010101));
+    }
+
+    #[test]
+    fn finds_pattern_repeated_any_number_of_times() {
+        assert!(contains_repeated_pattern(&111));
+        assert!(contains_repeated_pattern(&565656));
+        assert!(contains_repeated_pattern(&2121212121));
+        assert!(!contains_repeated_pattern(&7));
+        assert!(!contains_repeated_pattern(&1001));
+    }
 }
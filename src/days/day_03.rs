@@ -1,28 +1,52 @@
-pub fn day_03(input: &str, part: u8) {
-    let battery_count = if part == 1 { 2 } else { 12 };
-
-    let joltage_total = input.lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect::<Vec<u8>>()
-        })
-        .map(|digits| max_joltage(&digits, battery_count))
-        .fold(0, |acc, cur| acc + cur);
-
-    println!("joltage total: {}", joltage_total);
+use crate::digits::Digits;
+use crate::error::{Error, Result};
+use crate::scanner::Scanner;
+use crate::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+
+    // banks can hold different numbers of batteries, so each line is read on its own
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Scanner::new(input).each_line(|line| line.digits())
+    }
+
+    fn part_one(&self, banks: &Self::Input) -> Result<Answer> {
+        Ok(joltage_total(banks, 2)?.into())
+    }
+
+    fn part_two(&self, banks: &Self::Input) -> Result<Answer> {
+        Ok(joltage_total(banks, 12)?.into())
+    }
 }
 
-fn max_joltage(batteries: &[u8], battery_count: usize) -> u64 {
+pub fn joltage_total(banks: &[Vec<u8>], battery_count: usize) -> Result<u64> {
+    if let Some(bank) = banks.iter().find(|b| b.len() < battery_count) {
+        return Err(Error::solve(format!(
+            "bank of {} batteries can't supply {} batteries",
+            bank.len(),
+            battery_count)));
+    }
+
+    banks
+        .iter()
+        .try_fold(0u64, |total, digits| total.checked_add(max_joltage(digits, battery_count)?))
+        .ok_or_else(|| Error::solve(format!("joltage of {} batteries per bank doesn't fit in a u64", battery_count)))
+}
+
+// None if the joltage is too large for a u64
+pub fn max_joltage(batteries: &[u8], battery_count: usize) -> Option<u64> {
     // batteries must be used in order,
     // so if we need to use all available batteries then we can simply parse the input
     if battery_count == batteries.len() {
-        return digits_to_number(batteries);
+        return u64::from_digits(batteries.iter().copied(), 10);
     }
 
     // if we can only use 1 battery then we can take the highest individual battery
     if battery_count == 1 {
-        return *batteries.iter().max().expect("could not get max value from battery array") as u64;
+        return batteries.iter().copied().max().map(u64::from);
     }
 
     // find the highest digit in the valid search range
@@ -32,31 +56,23 @@ fn max_joltage(batteries: &[u8], battery_count: usize) -> u64 {
 
     // recurse through the remaining batteries (after our current max)
     let remaining_batteries = &batteries[max_index + 1..];
-    let remaining_joltage = max_joltage(remaining_batteries, battery_count - 1);
+    let remaining_joltage = max_joltage(remaining_batteries, battery_count - 1)?;
 
     // shift the current result left by the number of remaining batteries and combine them
-    let shift = 10u64.pow((battery_count - 1) as u32);
-    (max as u64 * shift) + remaining_joltage
+    let shift = 10u64.checked_pow((battery_count - 1) as u32)?;
+    (max as u64).checked_mul(shift)?.checked_add(remaining_joltage)
 }
 
 fn get_highest_individual_joltage(batteries: &[u8]) -> (u8, usize) {
     let mut max = 0u8;
     let mut max_index = usize::MIN;
 
-    for n in 0..batteries.len() {
-        let val = batteries[n];
-
+    for (n, &val) in batteries.iter().enumerate() {
         if val > max {
             max = val;
             max_index = n;
         }
     }
 
-    return (max, max_index);
-}
-
-fn digits_to_number(digits: &[u8]) -> u64 {
-    digits.iter()
-        // shift the acc left by 1 and add the new digit on the end
-        .fold(0u64, |acc, &digit| acc * 10 + digit as u64)
+    (max, max_index)
 }
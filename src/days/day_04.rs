@@ -1,169 +1,142 @@
-pub fn day_04(input: &str, part: u8) {
-    let width = input.lines().nth(0).unwrap().len() as u8;
-    let height = input.lines().count() as u8;
+use crate::error::Result;
+use crate::grid::{Grid, Point};
+use crate::solution::{Answer, Solution};
 
-    let rolls: Vec<bool> = input
-        .lines()
-        .flat_map(|line| line.chars().map(|c| c == '@'))
-        .collect();
+pub struct Day04;
+
+pub type RollMap = Grid<bool>;
+
+impl Solution for Day04 {
+    type Input = RollMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_roll_map(input)
+    }
+
+    fn part_one(&self, rolls: &Self::Input) -> Result<Answer> {
+        Ok(get_accessible_roll_count(rolls).into())
+    }
 
-    if part == 1 {
-        let sum = get_accessible_roll_count(&rolls, width, height);
-        println!("total accessible: {}", sum);
-    } else if part == 2 {
-        let total_removed = remove_accessible_rolls(rolls, width, height);
-        println!("total removed: {}", total_removed);
+    fn part_two(&self, rolls: &Self::Input) -> Result<Answer> {
+        Ok(remove_accessible_rolls(rolls.clone()).into())
     }
 }
 
-fn remove_accessible_rolls(mut rolls: Vec<bool>, width: u8, height: u8) -> u32 {
+pub fn parse_roll_map(input: &str) -> Result<RollMap> {
+    Grid::parse(input, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+pub fn remove_accessible_rolls(mut rolls: RollMap) -> u32 {
     let mut total_removed = 0;
 
-    while let Some(count) = remove_accessible_layer(&mut rolls, width, height) {
-        total_removed += count;
+    loop {
+        let count = remove_accessible_layer(&mut rolls);
+
         if count == 0 {
             break;
         }
+
+        total_removed += count;
     }
 
     total_removed
 }
 
-fn remove_accessible_layer(rolls: &mut Vec<bool>, width: u8, height: u8) -> Option<u32> {
-    let accessible: Vec<usize> = (0..rolls.len())
-        .filter(|&i| is_accessible(rolls, i as u32, width, height))
+fn remove_accessible_layer(rolls: &mut RollMap) -> u32 {
+    let accessible: Vec<Point> = rolls
+        .points()
+        .filter(|&point| is_accessible(rolls, point))
         .collect();
 
-    accessible.iter().for_each(|&i| rolls[i] = false);
-    
-    Some(accessible.len() as u32)
+    accessible.iter().for_each(|&point| rolls[point] = false);
+
+    accessible.len() as u32
 }
 
-fn is_accessible(roll_map: &Vec<bool>, idx: u32, width: u8, height: u8) -> bool {
-    roll_map[idx as usize]
-    && get_adjacent_roll_count(roll_map, idx as u32, width, height) < 4
+pub fn is_accessible(rolls: &RollMap, point: Point) -> bool {
+    rolls[point]
+    && get_adjacent_roll_count(rolls, point) < 4
 }
 
-fn get_accessible_roll_count(roll_map: &Vec<bool>, width: u8, height: u8) -> u32 {
-    (0..roll_map.len())
-        .filter(|i| is_accessible(roll_map, *i as u32, width, height))
+pub fn get_accessible_roll_count(rolls: &RollMap) -> u32 {
+    rolls
+        .points()
+        .filter(|&point| is_accessible(rolls, point))
         .count() as u32
 }
 
-fn get_adjacent_roll_count(roll_map: &Vec<bool>, idx: u32, width: u8, height: u8) -> u8 {
-    get_adjacent_indices(idx, width, height)
-        .iter()
-        .filter(|&&idx| roll_map[idx as usize])
+fn get_adjacent_roll_count(rolls: &RollMap, point: Point) -> u8 {
+    rolls
+        .neighbours8(point)
+        .filter(|&neighbour| rolls[neighbour])
         .count() as u8
 }
 
-fn get_adjacent_indices(idx: u32, width: u8, height: u8) -> Vec<u32> {
-    let x = idx % width as u32;
-    let y = idx / width as u32;
-
-    [-1, 0, 1]
-        .iter()
-        .flat_map(|&dy| {
-            [-1, 0, 1]
-                .iter()
-                .filter_map(move |&dx| {
-                    if dx == 0 && dy == 0 {
-                        return None;
-                    }
-
-                    let nx = (x as i32).checked_add(dx)?;
-                    let ny = (y as i32).checked_add(dy)?;
-
-                    if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
-                        return None;
-                    }
-
-                    Some(ny as u32 * width as u32 + nx as u32)
-                })
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod test {
     use super::{
-        get_adjacent_indices,
-        get_adjacent_roll_count
+        get_accessible_roll_count,
+        get_adjacent_roll_count,
+        parse_roll_map,
     };
+    use crate::grid::Grid;
 
     #[test]
-    fn gets_adjacent_indices_for_inner_idx() {
-        // 11 is (1, 1) on a 10x10 grid
-        let idx = 11;
-
-        let result = get_adjacent_indices(idx, 10, 10);
-
-        assert!(result.contains(&0));
-        assert!(result.contains(&1));
-        assert!(result.contains(&2));
-
-        assert!(result.contains(&10));
-        // our tile
-        assert!(result.contains(&12));
-        
-        assert!(result.contains(&20));
-        assert!(result.contains(&21));
-        assert!(result.contains(&22));
-
-        assert_eq!(result.len(), 8);
-    }
-
-    
-    #[test]
-    fn doesnt_include_out_of_bounds_adjacent_up_or_left() {
-        // 0 is (0, 0) on a 10x10 grid
-        let idx = 0;
-
-        let result = get_adjacent_indices(idx, 10, 10);
+    fn parses_input_correctly() {
+        let result = parse_roll_map("..@\n@@.").unwrap();
 
-        assert!(result.contains(&1));
-        assert!(result.contains(&10));
-        assert!(result.contains(&11));
-        
-        assert_eq!(result.len(), 3);
+        assert_eq!(result, Grid::new(vec![false, false, true, true, true, false], 3));
+        assert!(parse_roll_map("..@\n@x.").is_err());
     }
 
     #[test]
     fn gets_adjacent_roll_count_all_true() {
-        let roll_map = vec![
+        let roll_map = Grid::new(vec![
             true, true, true,
             true, true, true,
             true, true, true,
-        ];
+        ], 3);
 
-        let result = get_adjacent_roll_count(&roll_map, 4, 3, 3);
+        let result = get_adjacent_roll_count(&roll_map, (1, 1));
 
         assert_eq!(result, 8);
     }
 
     #[test]
     fn gets_adjacent_roll_count_all_false() {
-        let roll_map = vec![
+        let roll_map = Grid::new(vec![
             false, false, false,
             false, false, false,
             false, false, false,
-        ];
+        ], 3);
 
-        let result = get_adjacent_roll_count(&roll_map, 4, 3, 3);
+        let result = get_adjacent_roll_count(&roll_map, (1, 1));
 
         assert_eq!(result, 0);
     }
 
     #[test]
     fn gets_adjacent_roll_count_mixed() {
-        let roll_map = vec![
+        let roll_map = Grid::new(vec![
             false, true, false,
             false, false, true,
             true, false, true,
-        ];
+        ], 3);
 
-        let result = get_adjacent_roll_count(&roll_map, 4, 3, 3);
+        let result = get_adjacent_roll_count(&roll_map, (1, 1));
 
         assert_eq!(result, 4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn gets_accessible_roll_count() {
+        let roll_map = parse_roll_map("@@@\n@@@\n@@@").unwrap();
+
+        // only the corners have fewer than four neighbours
+        assert_eq!(get_accessible_roll_count(&roll_map), 4);
+    }
+}
@@ -1,100 +1,64 @@
 use std::ops::RangeInclusive;
 
-pub fn day_05(input: &str, part: u8) {
-    let (ranges, ingredients) = parse_input(input);
-
-    if part == 1 {
-        let fresh = ingredients_in_range(&ranges, &ingredients)
-            .count();
-    
-        println!("fresh ingredients: {}", fresh);
-    } else {
-        let fresh = distinct_covered_count(&ranges);
-
-        println!("fresh ingredients: {}", fresh);
-    }
-}
+use crate::error::{Error, Result};
+use crate::range_set::RangeSet;
+use crate::scanner::Scanner;
+use crate::solution::{Answer, Solution};
 
-fn parse_input(input: &str) -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
-    let mut ranges: Vec<RangeInclusive<u64>> = Vec::new();
-    let mut ingredients: Vec<u64> = Vec::new();
-
-    let mut is_taking_ranges = true;
-    for line in input.lines() {
-        if line.len() == 0 {
-            is_taking_ranges = false;
-            continue;
-        }
-
-        if is_taking_ranges {
-            let parts: Vec<u64> = line
-                .split('-')
-                .map(|x| x.parse::<u64>().expect("could not parse range part"))
-                .collect();
-
-            ranges.push(parts[0]..=parts[1]);
-        } else {
-            let value = line.parse::<u64>().expect("could not parse ingredient");
-
-            ingredients.push(value);
-        }
-    }
+pub struct Day05;
 
-    (ranges, ingredients)
-}
+impl Solution for Day05 {
+    type Input = (RangeSet<u64>, Vec<u64>);
 
-fn ingredients_in_range<'a>(
-    ranges: &'a Vec<RangeInclusive<u64>>,
-    ingredients: &'a Vec<u64>
-) -> impl Iterator<Item = &'a u64> {
-    ingredients
-        .iter()
-        .filter(|i| ranges.iter().any(|r| r.contains(i)))
-}
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let (ranges, ingredients) = parse_input(input)?;
 
-fn distinct_covered_count(ranges: &Vec<RangeInclusive<u64>>) -> u64 {
-    if ranges.len() == 0 {
-        return 0;
+        Ok((ranges.into_iter().collect(), ingredients))
     }
 
-    let mut ranges = ranges.clone();
-
-    // sort ranges by start, then end so we can merge them in one pass
-    ranges.sort_unstable_by(|a, b| {
-        a.start().cmp(b.start())
-        .then(a.end().cmp(b.end()))
-    });
-
-    let mut total = 0u64;
-
-    let mut cur_start = ranges[0].start();
-    let mut cur_end = ranges[0].end();
+    fn part_one(&self, (ranges, ingredients): &Self::Input) -> Result<Answer> {
+        Ok(ingredients_in_range(ranges, ingredients)
+            .count()
+            .into())
+    }
 
-    for r in &ranges[1..] {
-        let start = r.start();
-        let end = r.end();
+    fn part_two(&self, (ranges, _): &Self::Input) -> Result<Answer> {
+        let count = u64::try_from(ranges.len())
+            .map_err(|_| Error::solve("every ingredient id is fresh, which doesn't fit in a u64"))?;
 
-        // if the current range overlaps or touches the previous range, merge them
-        if start <= cur_end {
-            if end > cur_end {
-                cur_end = end;
-            }
+        Ok(count.into())
+    }
+}
 
-            continue;
-        }
+pub fn parse_input(input: &str) -> Result<(Vec<RangeInclusive<u64>>, Vec<u64>)> {
+    let mut sections = Scanner::new(input).sections().into_iter();
 
-        // add previous range to total and start a new range
-        total += cur_end - cur_start + 1;
-        cur_start = start;
-        cur_end = end;
-    }
+    let (Some(mut ranges), Some(mut ingredients), None) = (sections.next(), sections.next(), sections.next()) else {
+        return Err(Error::parse("expected ranges and ingredients separated by a blank line"));
+    };
 
-    // add the last range to total
-    total += cur_end - cur_start + 1;
+    Ok((
+        ranges.each_line(|line| line.unsigned_range())?,
+        ingredients.each_line(|line| line.unsigned())?,
+    ))
+}
 
-    total
+pub fn ingredients_in_range<'a>(
+    ranges: &'a RangeSet<u64>,
+    ingredients: &'a [u64]
+) -> impl Iterator<Item = &'a u64> {
+    ingredients
+        .iter()
+        .filter(|&&i| ranges.contains(i))
 }
 
+pub fn distinct_covered_count(ranges: &[RangeInclusive<u64>]) -> u128 {
+    ranges
+        .iter()
+        .cloned()
+        .collect::<RangeSet<u64>>()
+        .len()
+}
 
 #[cfg(test)]
 mod test {
@@ -103,6 +67,7 @@ mod test {
         ingredients_in_range,
         distinct_covered_count
     };
+    use crate::range_set::RangeSet;
 
     #[test]
     fn parses_input_correctly() {
@@ -114,7 +79,7 @@ mod test {
 5
 6";
 
-        let (ranges, ingredients) = parse_input(input);
+        let (ranges, ingredients) = parse_input(input).unwrap();
 
         assert_eq!(ranges.len(), 3);
         assert_eq!(ranges[0], 1..=3);
@@ -127,12 +92,18 @@ mod test {
         assert_eq!(ingredients[2], 6);
     }
 
+    #[test]
+    fn rejects_malformed_range() {
+        assert!(parse_input("1-x\n\n4").is_err());
+        assert!(parse_input("13\n\n4").is_err());
+    }
+
     #[test]
     fn filters_ingredients_in_range_correctly() {
-        let ranges = vec![
+        let ranges = RangeSet::from_iter([
             1..=3,
             5..=7,
-        ];
+        ]);
 
         let ingredients = vec![
             2,
@@ -151,7 +122,7 @@ mod test {
     #[test]
     fn returns_0_for_empty_ranges() {
         assert_eq!(
-            distinct_covered_count(&vec![]),
+            distinct_covered_count(&[]),
             0
         );
     }
@@ -159,7 +130,7 @@ mod test {
     #[test]
     fn returns_range_size_for_one_range() {
         assert_eq!(
-            distinct_covered_count(&vec![
+            distinct_covered_count(&[
                 1..=2
             ]),
             2
@@ -169,7 +140,7 @@ mod test {
     #[test]
     fn returns_total_range_size_for_two_non_overlapping_ranges() {
         assert_eq!(
-            distinct_covered_count(&vec![
+            distinct_covered_count(&[
                 1..=2,
                 3..=4,
             ]),
@@ -180,7 +151,7 @@ mod test {
     #[test]
     fn returns_total_range_size_for_two_partially_overlapping_ranges() {
         assert_eq!(
-            distinct_covered_count(&vec![
+            distinct_covered_count(&[
                 1..=3,
                 3..=4,
             ]),
@@ -191,7 +162,7 @@ mod test {
     #[test]
     fn returns_total_range_size_for_totally_overlapping_range() {
         assert_eq!(
-            distinct_covered_count(&vec![
+            distinct_covered_count(&[
                 1..=4,
                 3..=4,
             ]),
@@ -202,7 +173,7 @@ mod test {
     #[test]
     fn returns_total_range_size_for_test_data() {
         assert_eq!(
-            distinct_covered_count(&vec![
+            distinct_covered_count(&[
                 3..=5,
                 10..=14,
                 16..=20,
@@ -1,115 +1,187 @@
-pub fn day_06(input: &str, part: u8) {
-    let parser = if part == 1 { parse_input_pt1 } else { parse_input_pt2 };
+use std::ops::Range;
+
+use crate::error::{Error, Result};
+use crate::normalise::Normalise;
+use crate::scanner::Scanner;
+use crate::solution::{Answer, Solution};
+
+pub struct Day06;
+
+// the worksheet is read row-wise for part 1 and column-wise for part 2, so
+// it's kept as cells that either way can be built from
+pub struct Worksheet {
+    // the cells above the operators, column by column, None for a space.
+    // short rows are padded with spaces
+    digits: Vec<Vec<Option<u8>>>,
+    // the columns each problem spans, left to right, and its operator
+    problems: Vec<(Range<usize>, Operator)>,
+}
 
-    let results = parser(input)
-        .iter()
-        .map(|e| solve_equation(e))
-        .fold(0, |acc, cur| acc + cur);
+impl Solution for Day06 {
+    type Input = Worksheet;
 
-    println!("total of all results: {}", results);
-}
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_worksheet(input)
+    }
 
-type Equation = (Vec<u16>, Operator);
-
-fn parse_input_pt1(input: &str) -> Vec<Equation>{
-    let mut result: Vec<Equation> = 
-        input
-            .lines()
-            .last()
-            .unwrap()
-            .split_whitespace()
-            .map(|o| (vec![], if o == "*" { Operator::Multiply } else { Operator::Sum }))
-            .collect();
-
-    for line in input.lines().take(input.lines().count() - 1) {
-        for (i, digit) in line.split_whitespace().map(|d| d.parse::<u16>().unwrap()).enumerate() {
-            result[i].0.push(digit);
-        }
+    fn part_one(&self, worksheet: &Self::Input) -> Result<Answer> {
+        Ok(total_of_results(&worksheet.by_rows()?)?.into())
+    }
+
+    fn part_two(&self, worksheet: &Self::Input) -> Result<Answer> {
+        Ok(total_of_results(&worksheet.by_columns()?)?.into())
+    }
+
+    // columns are lined up with spaces, so trailing ones are part of the worksheet
+    fn normalisation(&self) -> Normalise {
+        Normalise { trailing_whitespace: false, ..Normalise::ALL }
     }
+}
 
-    result
+pub fn total_of_results(equations: &[Equation]) -> Result<u64> {
+    equations
+        .iter()
+        .try_fold(0u64, |total, equation| total.checked_add(solve_equation(equation)?))
+        .ok_or_else(|| Error::solve("total of the results doesn't fit in a u64"))
 }
 
-fn parse_input_pt2(input: &str) -> Vec<Equation>{
-    let rows: Vec<Vec<char>> = input
-        .lines()
-        .map(|l| l.chars().collect())
-        .collect();
+pub type Equation = (Vec<u16>, Operator);
 
-    let h = rows.len();
-    let w = rows[0].len();
-    for r in &rows {
-        assert_eq!(r.len(), w, "All lines must be the same width");
-    }
+impl Worksheet {
+    // each row of a problem holds one number, read left to right
+    pub fn by_rows(&self) -> Result<Vec<Equation>> {
+        let rows = self.digits.first().map_or(0, Vec::len);
 
-    let columns: Vec<Vec<char>> = (0..rows[0].len())
-        .map(|c|
-            rows
-                .iter()
-                .map(|r| r[c])
-                .collect())
-        .collect();
-
-    // split the columns into groups, separated when a column contains
-    // whitespace in all rows
-    let mut column_groups: Vec<Vec<usize>> = Vec::new();
-    let mut current_column_group: Vec<usize> = Vec::new();
-    for x in 0..w {
-        let is_empty_column = columns[x].iter().all(|&c| c == ' ');
-
-        if is_empty_column {
-            if !current_column_group.is_empty() {
-                column_groups.push(current_column_group);
-                current_column_group = vec![];
-            }
-        } else {
-            current_column_group.push(x);
-        }
+        self.problems
+            .iter()
+            .map(|(columns, operator)| {
+                let nums = (0..rows)
+                    .filter_map(|y| number(columns.clone().map(|x| self.digits[x][y])))
+                    .collect::<Result<_>>()?;
+
+                Ok((nums, *operator))
+            })
+            .collect()
     }
-    if !current_column_group.is_empty() {
-        column_groups.push(current_column_group);
+
+    // each column of a problem holds one number, read top to bottom
+    pub fn by_columns(&self) -> Result<Vec<Equation>> {
+        self.problems
+            .iter()
+            .map(|(columns, operator)| {
+                // not strictly necessary but their example goes right-to-left
+                let nums = columns
+                    .clone()
+                    .rev()
+                    .filter_map(|x| number(self.digits[x].iter().copied()))
+                    .collect::<Result<_>>()?;
+
+                Ok((nums, *operator))
+            })
+            .collect()
     }
+}
 
-    column_groups
-        .into_iter()
-        .map(|idxs| {
-            let operator = match columns[idxs[0]][h - 1] {
-                '*' => Operator::Multiply,
-                '+' => Operator::Sum,
-                _ => panic!("could not find operator in column group"),
-            };
+// the number the digits spell out, skipping spaces, None if there are no digits
+fn number(cells: impl Iterator<Item = Option<u8>>) -> Option<Result<u16>> {
+    let mut digits = cells.flatten().peekable();
 
-            let mut nums: Vec<u16> = idxs
+    digits.peek()?;
+
+    let number = digits
+        .try_fold(0u16, |acc, digit| acc.checked_mul(10)?.checked_add(digit as u16))
+        .ok_or_else(|| Error::solve("a number in the worksheet doesn't fit in a u16"));
+
+    Some(number)
+}
+
+pub fn parse_worksheet(input: &str) -> Result<Worksheet> {
+    let mut scanner = Scanner::new(input);
+    // short rows are padded with empty cells, which count as spaces
+    let columns = scanner.columns();
+    let h = columns
+        .first()
+        .map(Vec::len)
+        .ok_or_else(|| Error::parse("worksheet is empty"))?;
+
+    let is_space = |cell: &str| matches!(cell, "" | " ");
+
+    let digits = columns
+        .iter()
+        .map(|column| {
+            column[..h - 1]
                 .iter()
-                .map(|&i| {
-                    // get all digits in this column, join them into a single string and parse
-                    columns[i][0..h - 1]
-                        .iter()
-                        .filter(|c| c.is_ascii_digit())
-                        .collect::<String>()
-                        .parse::<u16>()
-                        .unwrap_or(0)
+                .map(|&cell| {
+                    if is_space(cell) {
+                        return Ok(None);
+                    }
+
+                    cell.chars()
+                        .next()
+                        .and_then(|c| c.to_digit(10))
+                        .map(|d| Some(d as u8))
+                        .ok_or_else(|| scanner.error_at(cell, format!("expected a digit or a space, found '{}'", cell)))
                 })
-                .collect();
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-            // not strictly necessary but their example goes right-to-left
-            nums.reverse();
+    // problems are separated by columns that are spaces all the way down
+    let is_separator = |x: usize| columns[x].iter().all(|&cell| is_space(cell));
+    let mut problems = vec![];
+    let mut x = 0;
 
-            (nums, operator)
-        })
-        .collect()
+    while x < columns.len() {
+        if is_separator(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+
+        while x < columns.len() && !is_separator(x) {
+            x += 1;
+        }
+
+        let operator = match columns[start][h - 1] {
+            "*" => Operator::Multiply,
+            "+" => Operator::Sum,
+            cell => return Err(scanner.error_at(cell, "expected an operator at the start of the column group")),
+        };
+
+        if let Some(cell) = columns[start + 1..x].iter().map(|column| column[h - 1]).find(|&cell| !is_space(cell)) {
+            return Err(scanner.error_at(cell, format!("unexpected '{}', each column group has a single operator", cell)));
+        }
+
+        // a row's digits in a problem make a single number, so they can't have gaps
+        for y in 0..h - 1 {
+            let stray = (start..x)
+                .skip_while(|&x| digits[x][y].is_none())
+                .skip_while(|&x| digits[x][y].is_some())
+                .find(|&x| digits[x][y].is_some());
+
+            if let Some(x) = stray {
+                return Err(scanner.error_at(columns[x][y], "expected one number per row in each column group"));
+            }
+        }
+
+        problems.push((start..x, operator));
+    }
+
+    Ok(Worksheet { digits, problems })
 }
 
-fn solve_equation((numbers, operator): &Equation) -> u64 {
+// None if the result is too large for a u64
+pub fn solve_equation((numbers, operator): &Equation) -> Option<u64> {
     numbers
         .iter()
-        .fold(
+        .try_fold(
             if operator == &Operator::Multiply { 1 } else { 0 },
-            |acc, &cur| if operator == &Operator::Multiply { acc * cur as u64 } else { acc + cur as u64 })
+            |acc: u64, &cur| if operator == &Operator::Multiply { acc.checked_mul(cur as u64) } else { acc.checked_add(cur as u64) })
 }
 
-#[derive(Debug, PartialEq)]
-enum Operator {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
     Sum,
     Multiply
 }
@@ -117,21 +189,24 @@ enum Operator {
 #[cfg(test)]
 mod test {
     use super::{
+        Day06,
         Equation,
         Operator,
-        parse_input_pt1,
-        parse_input_pt2,
+        parse_worksheet,
         solve_equation,
+        total_of_results,
     };
+    use crate::error::{Error, Location};
+    use crate::solution::{Answer, Solution};
 
     #[test]
-    fn parses_input_pt1_correctly() {
+    fn reads_equations_by_rows() {
         let input = "123 328  51 64 
  45 64  387 23 
   6 98  215 314
 *   +   *   +  ";
 
-        let result = parse_input_pt1(input);
+        let result = parse_worksheet(input).unwrap().by_rows().unwrap();
 
         let expected: Vec<Equation> = vec![
             (vec![123, 45, 6], Operator::Multiply),
@@ -147,13 +222,13 @@ mod test {
     }
 
     #[test]
-    fn parses_input_pt2_correctly() {
+    fn reads_equations_by_columns() {
         let input = "123 328  51 64 
  45 64  387 23 
   6 98  215 314
 *   +   *   +  ";
 
-        let result = parse_input_pt2(input);
+        let result = parse_worksheet(input).unwrap().by_columns().unwrap();
 
         let expected: Vec<Equation> = vec![
             (vec![356, 24, 1], Operator::Multiply),
@@ -168,6 +243,55 @@ mod test {
         );
     }
 
+    #[test]
+    fn reads_equations_by_columns_with_ragged_rows() {
+        let input = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +";
+
+        let result = parse_worksheet(input).unwrap().by_columns().unwrap();
+
+        assert_eq!(result[0], (vec![356, 24, 1], Operator::Multiply));
+        assert_eq!(result[3], (vec![4, 431, 623], Operator::Sum));
+    }
+
+    #[test]
+    fn solves_both_parts_with_ragged_rows() {
+        let worksheet = Day06.parse("123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +").unwrap();
+
+        assert_eq!(Day06.part_one(&worksheet).unwrap(), Answer::Number(4277556));
+        assert_eq!(Day06.part_two(&worksheet).unwrap(), Answer::Number(3263827));
+    }
+
+    #[test]
+    fn points_at_stray_characters_in_columns() {
+        let Err(Error::Parse(e)) = parse_worksheet("1x 2\n3  4\n*  +") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(e.message, "expected a digit or a space, found 'x'");
+        assert_eq!(e.location, Some(Location { line: 1, column: 2, text: String::from("1x 2"), width: 1 }));
+    }
+
+    #[test]
+    fn points_at_a_second_number_in_a_row() {
+        let Err(Error::Parse(e)) = parse_worksheet("1 2\n345\n+  ") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(e.location, Some(Location { line: 1, column: 3, text: String::from("1 2"), width: 1 }));
+    }
+
+    #[test]
+    fn points_at_unknown_operator() {
+        let Err(Error::Parse(e)) = parse_worksheet("1 2\n3 4\n* -") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(e.location, Some(Location { line: 3, column: 3, text: String::from("* -"), width: 1 }));
+    }
+
     #[test]
     fn solves_sum_equation() {
         let equation: Equation = (
@@ -178,7 +302,7 @@ mod test {
         let result = solve_equation(&equation);
 
         assert_eq!(
-            6,
+            Some(6),
             result,
         );
     }
@@ -193,8 +317,19 @@ mod test {
         let result = solve_equation(&equation);
 
         assert_eq!(
-            6,
+            Some(6),
             result,
         );
     }
+
+    #[test]
+    fn rejects_results_too_large_for_a_u64() {
+        let equation: Equation = (
+            vec![9999; 5],
+            Operator::Multiply
+        );
+
+        assert_eq!(solve_equation(&equation), None);
+        assert!(total_of_results(&[equation]).is_err());
+    }
 }
\ No newline at end of file
@@ -1,59 +1,87 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-pub fn day_07(input: &str, part: u8) {
-    let mut lines = input.lines();
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Point};
+use crate::memo::Memo;
+use crate::solution::{Answer, Solution};
 
-    let first_line = lines.next().unwrap();
+pub struct Day07;
 
-    let beams: Vec<usize> = get_indices_of_char(
-        first_line,
-        'S'
-    );
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Empty,
+    Start,
+    Splitter,
+}
 
-    let splitter_lines: Vec<Vec<usize>> = lines
-        .map(|l| get_indices_of_char(l, '^'))
-        .collect();
+pub type Manifold = Grid<Tile>;
 
-    let total = if part == 1 {
-        get_total_split_count(&beams, &splitter_lines) as u64
-    } else {
-        get_total_timeline_count(beams[0], &splitter_lines)
-    };
+impl Solution for Day07 {
+    type Input = Manifold;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_manifold(input)
+    }
 
-    println!("total {}", total);
+    fn part_one(&self, manifold: &Self::Input) -> Result<Answer> {
+        Ok(get_total_split_count(manifold)?.into())
+    }
+
+    fn part_two(&self, manifold: &Self::Input) -> Result<Answer> {
+        Ok(get_total_timeline_count(manifold)?.into())
+    }
 }
 
-fn get_indices_of_char(line: &str, char: char) -> Vec<usize> {
-    line
-        .chars()
-        .enumerate()
-        .filter_map(|(i, c)| 
-            if c == char {
-                Some(i)
-            } else {
-                None
-            }
-        )
+pub fn parse_manifold(input: &str) -> Result<Manifold> {
+    let manifold = Grid::parse(input, |c| match c {
+        '.' => Some(Tile::Empty),
+        'S' => Some(Tile::Start),
+        '^' => Some(Tile::Splitter),
+        _ => None,
+    })?;
+
+    if get_beam_starts(&manifold).is_empty() {
+        let first_line = input.lines().next().unwrap_or(input);
+
+        return Err(Error::parse_at(input, first_line, "first line has no beam start 'S'"));
+    }
+
+    Ok(manifold)
+}
+
+// beams start from every 'S' on the first row
+pub fn get_beam_starts(manifold: &Manifold) -> Vec<usize> {
+    manifold
+        .find_all(&Tile::Start)
+        .take_while(|&(_, y)| y == 0)
+        .map(|(x, _)| x)
         .collect()
 }
 
-fn get_new_beam_positions(beams: &Vec<usize>, splitters: &Vec<usize>) -> (Vec<usize>, u32) {
+pub fn get_new_beam_positions(beams: &[usize], row: &[Tile]) -> Result<(Vec<usize>, u32)> {
     let mut new_beams: Vec<usize> = vec![];
     let mut split_count: u32 = 0;
 
-    for beam in beams {
-        if splitters.contains(beam) {
-            new_beams.push(beam - 1);
+    // a beam past the right edge has nothing left to hit, so it carries on
+    for &beam in beams {
+        if row.get(beam) == Some(&Tile::Splitter) {
+            new_beams.push(beam.checked_sub(1).ok_or_else(split_off_left_edge)?);
             new_beams.push(beam + 1);
             split_count += 1;
         } else {
-            new_beams.push(*beam);
+            new_beams.push(beam);
         }
     }
     
     dedupe(&mut new_beams);
 
-    (new_beams, split_count)
+    Ok((new_beams, split_count))
+}
+
+const LEFT_EDGE: &str = "a beam was split off the left edge of the manifold";
+
+fn split_off_left_edge() -> Error {
+    Error::solve(LEFT_EDGE)
 }
 
 fn dedupe(v: &mut Vec<usize>) {
@@ -62,72 +90,92 @@ fn dedupe(v: &mut Vec<usize>) {
     v.retain(|&x| set.insert(x));
 }
 
-fn get_total_split_count(beams: &Vec<usize>, splitter_lines: &Vec<Vec<usize>>) -> u32 {
-    let mut beams = beams.clone();
+pub fn get_total_split_count(manifold: &Manifold) -> Result<u32> {
+    let mut beams = get_beam_starts(manifold);
     let mut total = 0;
     
-    for splitters in splitter_lines {
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+    for row in manifold.rows().skip(1) {
+        let (new_beams, split_count) = get_new_beam_positions(&beams, row)?;
 
         beams = new_beams;
         total += split_count;
     }
 
-    total
+    Ok(total)
 }
 
-fn get_total_timeline_count(beam: usize, splitter_lines: &Vec<Vec<usize>>) -> u64 {
-    let mut memo: HashMap<(usize, usize), u64> = HashMap::new();
-
-    count_timelines_from(splitter_lines, &mut memo, beam, 0)
+pub fn get_total_timeline_count(manifold: &Manifold) -> Result<u64> {
+    count_timelines_from(manifold, &mut Memo::new(), (get_beam_starts(manifold)[0], 1))
+        .map_err(Error::solve)
 }
 
+// the error is a message rather than an Error so the memo can clone it
 fn count_timelines_from(
-    splitter_lines: &Vec<Vec<usize>>,
-    memo: &mut HashMap<(usize, usize), u64>,
-    beam: usize,
-    row: usize,
-) -> u64 {
-    if row >= splitter_lines.len() {
-        return 1;
+    manifold: &Manifold,
+    memo: &mut Memo<Point, std::result::Result<u64, &'static str>>,
+    (beam, row): Point,
+) -> std::result::Result<u64, &'static str> {
+    if row >= manifold.height() {
+        return Ok(1);
     }
 
     // cache by (beam and row) so we don't recalculate
-    if let Some(&cached) = memo.get(&(beam, row)) {
-        return cached;
-    }
+    memo.get_or_insert_with((beam, row), |memo| {
+        let next_row = row + 1;
 
-    let splitters = &splitter_lines[row];
-    let next_row = row + 1;
+        if manifold.get((beam, row)) == Some(&Tile::Splitter) {
+            let left_beam = beam.checked_sub(1).ok_or(LEFT_EDGE)?;
+            let left = count_timelines_from(manifold, memo, (left_beam, next_row))?;
+            let right = count_timelines_from(manifold, memo, (beam + 1, next_row))?;
 
-    let result = if splitters.contains(&beam) {
-        let left = count_timelines_from(splitter_lines, memo, beam - 1, next_row);
-        let right = count_timelines_from(splitter_lines, memo, beam + 1, next_row);
-
-        left + right
-    } else {
-        count_timelines_from(splitter_lines, memo, beam, next_row)
-    };
-
-    memo.insert((beam, row), result);
-
-    result
+            left.checked_add(right).ok_or("timeline count doesn't fit in a u64")
+        } else {
+            count_timelines_from(manifold, memo, (beam, next_row))
+        }
+    })
 }
 
 #[cfg(test)]
 mod test {
     use super::{
+        Tile,
+        get_beam_starts,
         get_new_beam_positions,
-        get_indices_of_char,
         get_total_split_count,
         get_total_timeline_count,
+        parse_manifold,
     };
+    use crate::error::Error;
+
+    const EXAMPLE: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    // a row 15 wide with splitters at the given columns
+    fn row(splitters: &[usize]) -> Vec<Tile> {
+        (0..15)
+            .map(|x| if splitters.contains(&x) { Tile::Splitter } else { Tile::Empty })
+            .collect()
+    }
 
     #[test]
     fn parses_start_point() {
         let input = ".......S.......";
 
-        let result = get_indices_of_char(input, 'S');
+        let result = get_beam_starts(&parse_manifold(input).unwrap());
 
         assert_eq!(
             result,
@@ -137,9 +185,13 @@ mod test {
 
     #[test]
     fn parses_splitters() {
-        let input = ".^.^.^.^.^...^.";
+        let input = ".......S.......\n.^.^.^.^.^...^.";
 
-        let result = get_indices_of_char(input, '^');
+        let manifold = parse_manifold(input).unwrap();
+        let result: Vec<usize> = manifold
+            .find_all(&Tile::Splitter)
+            .map(|(x, _)| x)
+            .collect();
 
         assert_eq!(
             result,
@@ -147,12 +199,18 @@ mod test {
         )
     }
 
+    #[test]
+    fn rejects_manifold_without_start() {
+        assert!(parse_manifold("...\n.^.").is_err());
+        assert!(parse_manifold("..S\n.#.").is_err());
+    }
+
     #[test]
     fn continues_single_beam_downwards_if_no_splitters() {
         let beams: Vec<usize> = vec![7];
-        let splitters: Vec<usize> = vec![];
+        let splitters = row(&[]);
 
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters).unwrap();
 
         assert_eq!(new_beams, beams);
         assert_eq!(split_count, 0);
@@ -161,12 +219,12 @@ mod test {
     #[test]
     fn continues_single_beam_downwards_if_doesnt_hit_splitter() {
         let beams: Vec<usize> = vec![7];
-        let splitters: Vec<usize> = vec![
+        let splitters = row(&[
             0, 1, 2, 3, 4, 5, 6,
             8, 9, 10, 11, 12, 13, 14
-        ];
+        ]);
 
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters).unwrap();
 
         assert_eq!(new_beams, beams);
         assert_eq!(split_count, 0);
@@ -175,9 +233,9 @@ mod test {
     #[test]
     fn splits_single_beam() {
         let beams: Vec<usize> = vec![7];
-        let splitters: Vec<usize> = vec![7];
+        let splitters = row(&[7]);
 
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters).unwrap();
 
         assert_eq!(new_beams, vec![6, 8]);
         assert_eq!(split_count, 1);
@@ -186,9 +244,9 @@ mod test {
     #[test]
     fn continues_multiple_beams_downwards_if_no_splitters() {
         let beams: Vec<usize> = vec![4, 10];
-        let splitters: Vec<usize> = vec![];
+        let splitters = row(&[]);
         
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters).unwrap();
 
         assert_eq!(new_beams, beams);
         assert_eq!(split_count, 0);
@@ -197,9 +255,9 @@ mod test {
     #[test]
     fn continues_multiple_beams_downwards_if_doesnt_hit_splitter() {
         let beams: Vec<usize> = vec![4, 10];
-        let splitters: Vec<usize> = vec![7];
+        let splitters = row(&[7]);
         
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters).unwrap();
 
         assert_eq!(new_beams, beams);
         assert_eq!(split_count, 0);
@@ -208,9 +266,9 @@ mod test {
     #[test]
     fn splits_multiple_beams_distinctly() {
         let beams: Vec<usize> = vec![1, 3, 4, 5, 7, 8, 10, 11, 13];
-        let splitters: Vec<usize> = vec![1, 3, 5, 7, 9, 13];
+        let splitters = row(&[1, 3, 5, 7, 9, 13]);
         
-        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters);
+        let (new_beams, split_count) = get_new_beam_positions(&beams, &splitters).unwrap();
 
         assert_eq!(
             new_beams,
@@ -222,28 +280,9 @@ mod test {
     #[test]
     fn gets_total_split_count() {
         // data from part 1 example
-        let splitter_groups: Vec<Vec<usize>> = vec![
-            vec![],
-            vec![7],
-            vec![],
-            vec![6, 8],
-            vec![],
-            vec![5, 7, 9],
-            vec![],
-            vec![4, 6, 10],
-            vec![],
-            vec![3, 5, 9, 11],
-            vec![],
-            vec![2, 6, 12],
-            vec![],
-            vec![1, 3, 5, 7, 9, 13],
-            vec![],
-        ];
+        let manifold = parse_manifold(EXAMPLE).unwrap();
 
-        let total = get_total_split_count(
-            &vec![7],
-            &splitter_groups
-        );
+        let total = get_total_split_count(&manifold).unwrap();
 
         assert_eq!(total, 21);
     }
@@ -251,29 +290,39 @@ mod test {
     #[test]
     fn gets_total_timeline_count() {
         // data from part 1 example
-        let splitter_groups: Vec<Vec<usize>> = vec![
-            vec![],
-            vec![7],
-            vec![],
-            vec![6, 8],
-            vec![],
-            vec![5, 7, 9],
-            vec![],
-            vec![4, 6, 10],
-            vec![],
-            vec![3, 5, 9, 11],
-            vec![],
-            vec![2, 6, 12],
-            vec![],
-            vec![1, 3, 5, 7, 9, 13],
-            vec![],
-        ];
+        let manifold = parse_manifold(EXAMPLE).unwrap();
 
-        let total = get_total_timeline_count(
-            7,
-            &splitter_groups,
-        );
+        let total = get_total_timeline_count(&manifold).unwrap();
 
         assert_eq!(total, 40);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn keeps_beams_split_past_the_right_edge() {
+        let manifold = parse_manifold("..S\n..^\n...\n...").unwrap();
+
+        assert_eq!(get_total_split_count(&manifold).unwrap(), 1);
+        assert_eq!(get_total_timeline_count(&manifold).unwrap(), 2);
+    }
+
+    #[test]
+    fn rejects_timeline_count_too_large_for_a_u64() {
+        // every splitter doubles the timelines, so 100 rows of them make 2^100
+        let mut input = format!("{}S{}", ".".repeat(200), ".".repeat(200));
+        input.push_str(&format!("\n{}", "^".repeat(401)).repeat(100));
+
+        let manifold = parse_manifold(&input).unwrap();
+
+        assert!(matches!(
+            get_total_timeline_count(&manifold),
+            Err(Error::Solve(message)) if message == "timeline count doesn't fit in a u64"));
+    }
+
+    #[test]
+    fn rejects_beams_split_off_the_left_edge() {
+        let manifold = parse_manifold("S..\n...\n^..").unwrap();
+
+        assert!(matches!(get_total_split_count(&manifold), Err(Error::Solve(_))));
+        assert!(matches!(get_total_timeline_count(&manifold), Err(Error::Solve(_))));
+    }
+}
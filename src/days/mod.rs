@@ -1,7 +1,16 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
\ No newline at end of file
+use crate::solution::DynSolution;
+
+// `pub mod day_NN;` for every src/days/day_NN.rs and the DAYS registry,
+// in day order. both are generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub fn get_solution(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, solution)| solution)
+}
+
+pub fn implemented_days() -> impl Iterator<Item = u8> {
+    DAYS.iter().map(|&(day, _)| day)
+}
@@ -0,0 +1,211 @@
+// digit level helpers for unsigned numbers in any base from 2 to 256, without
+// going through strings. digits are u8s, so other bases panic
+pub trait Digits: Sized + Copy {
+    // most significant first, 0 has the single digit 0
+    fn digits(self, base: u32) -> DigitIter<Self>;
+    fn digit_count(self, base: u32) -> u32;
+    // the number written by `digits`, most significant first, None on overflow.
+    // panics on a digit that's not below the base
+    fn from_digits(digits: impl IntoIterator<Item = u8>, base: u32) -> Option<Self>;
+    // the digits of self followed by those of other, e.g. 12 and 345 make 12345
+    fn concat(self, other: Self, base: u32) -> Option<Self>;
+    // self written as a block of `block_len` digits, `times` times over,
+    // e.g. 12 as 3 digits twice is 012012
+    fn repeat_block(self, block_len: u32, times: u32, base: u32) -> Option<Self>;
+    // whether the digits are one block repeated exactly `times` times, e.g.
+    // 123123 is 123 twice. the block can't start with a 0
+    fn is_repeated_block(self, times: u32, base: u32) -> bool;
+}
+
+#[derive(Debug, Clone)]
+pub struct DigitIter<T> {
+    value: T,
+    // the place value of the next digit, 0 once they're all read
+    place: T,
+    base: T,
+}
+
+fn check_base(base: u32) {
+    assert!((2..=256).contains(&base), "base must be from 2 to 256, got {}", base);
+}
+
+macro_rules! impl_digits {
+    ($($t:ty),*) => {
+        $(
+            impl Digits for $t {
+                fn digits(self, base: u32) -> DigitIter<Self> {
+                    let base = base as $t;
+
+                    DigitIter { value: self, place: base.pow(self.digit_count(base as u32) - 1), base }
+                }
+
+                fn digit_count(self, base: u32) -> u32 {
+                    check_base(base);
+
+                    self.checked_ilog(base as $t).map_or(1, |log| log + 1)
+                }
+
+                fn from_digits(digits: impl IntoIterator<Item = u8>, base: u32) -> Option<Self> {
+                    check_base(base);
+
+                    digits
+                        .into_iter()
+                        .try_fold(0 as $t, |acc, digit| {
+                            assert!((digit as u32) < base, "{} isn't a digit in base {}", digit, base);
+
+                            acc.checked_mul(base as $t)?.checked_add(digit as $t)
+                        })
+                }
+
+                fn concat(self, other: Self, base: u32) -> Option<Self> {
+                    (base as $t)
+                        .checked_pow(other.digit_count(base))
+                        .and_then(|shift| self.checked_mul(shift))
+                        .and_then(|shifted| shifted.checked_add(other))
+                }
+
+                fn repeat_block(self, block_len: u32, times: u32, base: u32) -> Option<Self> {
+                    check_base(base);
+
+                    // the shift is only needed from the second block on, so a
+                    // single block as long as the type allows still fits
+                    let shift = (base as $t).checked_pow(block_len);
+
+                    match times {
+                        0 => Some(0),
+                        _ => (1..times).try_fold(self, |acc, _| acc.checked_mul(shift?)?.checked_add(self)),
+                    }
+                }
+
+                fn is_repeated_block(self, times: u32, base: u32) -> bool {
+                    let count = self.digit_count(base);
+
+                    if times == 0 || !count.is_multiple_of(times) {
+                        return false;
+                    }
+
+                    let block_len = count / times;
+                    // a block too long for its place value to fit is the whole number
+                    let block = (base as $t).checked_pow(block_len).map_or(self, |place| self % place);
+
+                    // a block with a leading 0 is shorter, so repeating it can't match
+                    block.repeat_block(block_len, times, base) == Some(self)
+                }
+            }
+
+            impl Iterator for DigitIter<$t> {
+                type Item = u8;
+
+                fn next(&mut self) -> Option<u8> {
+                    if self.place == 0 {
+                        return None;
+                    }
+
+                    let digit = self.value / self.place % self.base;
+                    self.place /= self.base;
+
+                    Some(digit as u8)
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u64, u128);
+
+#[cfg(test)]
+mod test {
+    use super::Digits;
+
+    #[test]
+    fn iterates_digits_in_any_base() {
+        assert_eq!(1234u64.digits(10).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(0u64.digits(10).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(10u64.digits(2).collect::<Vec<_>>(), vec![1, 0, 1, 0]);
+        assert_eq!(u128::MAX.digits(16).count(), 32);
+        assert!(u64::MAX.digits(10).eq(u64::MAX.to_string().bytes().map(|b| b - b'0')));
+    }
+
+    #[test]
+    fn counts_digits() {
+        assert_eq!(0u64.digit_count(10), 1);
+        assert_eq!(9u64.digit_count(10), 1);
+        assert_eq!(10u64.digit_count(10), 2);
+        assert_eq!(u64::MAX.digit_count(10), 20);
+        assert_eq!(u128::MAX.digit_count(10), 39);
+        assert_eq!(255u64.digit_count(2), 8);
+    }
+
+    #[test]
+    fn builds_numbers_from_digits() {
+        assert_eq!(u64::from_digits([9, 8, 7], 10), Some(987));
+        assert_eq!(u64::from_digits([1, 0, 1], 2), Some(5));
+        assert_eq!(u64::from_digits([], 10), Some(0));
+        assert_eq!(u64::from_digits([9; 20], 10), None);
+        assert_eq!(u128::from_digits([9; 20], 10), Some(99_999_999_999_999_999_999));
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be from 2 to 256, got 257")]
+    fn rejects_bases_too_large_for_u8_digits() {
+        1000u64.digits(257).for_each(drop);
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be from 2 to 256, got 1")]
+    fn rejects_bases_too_small_when_repeating() {
+        1u64.repeat_block(1, 2, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "10 isn't a digit in base 10")]
+    fn rejects_digits_not_below_the_base() {
+        u64::from_digits([1, 10], 10);
+    }
+
+    #[test]
+    fn concatenates_numbers() {
+        assert_eq!(12u64.concat(345, 10), Some(12345));
+        assert_eq!(12u64.concat(0, 10), Some(120));
+        assert_eq!(0b11u64.concat(0b01, 2), Some(0b111));
+        assert_eq!(u64::MAX.concat(1, 10), None);
+    }
+
+    #[test]
+    fn repeats_blocks() {
+        assert_eq!(12u64.repeat_block(2, 3, 10), Some(121212));
+        assert_eq!(12u64.repeat_block(3, 2, 10), Some(12012));
+        assert_eq!(7u64.repeat_block(1, 0, 10), Some(0));
+        assert_eq!(123u64.repeat_block(3, 7, 10), None);
+        assert_eq!(123u128.repeat_block(3, 7, 10), Some(123_123_123_123_123_123_123));
+    }
+
+    #[test]
+    fn detects_repeated_blocks() {
+        assert!(1212u64.is_repeated_block(2, 10));
+        assert!(111u64.is_repeated_block(3, 10));
+        assert!(824824824u64.is_repeated_block(3, 10));
+        assert!(5u64.is_repeated_block(1, 10));
+        assert!(!1212u64.is_repeated_block(4, 10));
+        assert!(!121u64.is_repeated_block(2, 10));
+        assert!(!1001u64.is_repeated_block(2, 10));
+        assert!(0b1010u64.is_repeated_block(2, 2));
+        assert!(u64::MAX.is_repeated_block(1, 10));
+        assert!(u128::MAX.is_repeated_block(1, 10));
+        assert!(!u64::MAX.is_repeated_block(2, 10));
+        assert_eq!(u64::MAX.repeat_block(20, 1, 10), Some(u64::MAX));
+    }
+
+    #[test]
+    fn matches_string_repetition() {
+        for n in 0..20_000u64 {
+            let s = n.to_string();
+
+            for times in 1..=5 {
+                let expected = s.len() % times == 0 && s[..s.len() / times].repeat(times) == s;
+
+                assert_eq!(n.is_repeated_block(times as u32, 10), expected, "{} repeated {} times", n, times);
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: Option<PathBuf>, source: io::Error },
+    BadArguments(String),
+    UnknownDay(u8),
+    Parse(ParseError),
+    // the input parsed but the solver couldn't produce an answer from it
+    Solve(String),
+    // talking to the puzzle server failed
+    Http(String),
+    MissingSession,
+    MissingBaseUrl,
+    MissingContact,
+    // `new` won't overwrite an existing day
+    AlreadyExists(PathBuf),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+    // the file the input came from, filled in by the runner
+    pub input_name: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Location {
+    // both 1-based, the column counts chars rather than bytes
+    pub line: usize,
+    pub column: usize,
+    // the whole line containing the bad input
+    pub text: String,
+    // how many chars to underline
+    pub width: usize,
+}
+
+impl Location {
+    // `span` has to be a slice of `input`, e.g. from `lines` or `split_once`,
+    // anything else has no location
+    pub fn of(input: &str, span: &str) -> Option<Location> {
+        let start = (span.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+
+        if start + span.len() > input.len() {
+            return None;
+        }
+
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+
+        Some(Location {
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            width: span.chars().take_while(|&c| c != '\n').count().max(1),
+        })
+    }
+}
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Error {
+        Error::Io { path: Some(path.into()), source }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Error {
+        Error::Parse(ParseError { message: message.into(), location: None, input_name: None })
+    }
+
+    // a parse error pointing at `span`, which must be a slice of `input`
+    pub fn parse_at(input: &str, span: &str, message: impl Into<String>) -> Error {
+        Error::Parse(ParseError { message: message.into(), location: Location::of(input, span), input_name: None })
+    }
+
+    // names the input a parse error came from, other errors are left alone
+    pub fn in_input(self, name: impl Into<String>) -> Error {
+        match self {
+            Error::Parse(e) => Error::Parse(ParseError { input_name: Some(name.into()), ..e }),
+            other => other,
+        }
+    }
+
+    pub fn solve(message: impl Into<String>) -> Error {
+        Error::Solve(message.into())
+    }
+
+    // 1 is left for all, verify, bench --compare and submit failures
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::BadArguments(_) => 2,
+            Error::UnknownDay(_) => 3,
+            Error::Io { .. } => 4,
+            Error::Parse(_) => 5,
+            Error::Solve(_) => 6,
+            Error::Http(_) => 7,
+            Error::MissingSession => 8,
+            Error::AlreadyExists(_) => 9,
+            Error::MissingBaseUrl => 10,
+            Error::MissingContact => 11,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path: Some(path), source } => write!(f, "{}: {}", path.display(), source),
+            Error::Io { path: None, source } => write!(f, "{}", source),
+            Error::BadArguments(message) => write!(f, "{}", message),
+            Error::UnknownDay(day) => write!(f, "day {} is not implemented yet", day),
+            Error::Parse(e) => write!(f, "could not parse input: {}", e),
+            Error::Solve(message) => write!(f, "could not solve: {}", message),
+            Error::Http(message) => write!(f, "{}", message),
+            Error::MissingSession => write!(f, "no session token, set AOC_SESSION or put it in .session"),
+            Error::MissingBaseUrl => write!(
+                f,
+                "no server to talk to, set AOC_BASE_URL or base_url in aoc.toml to an http:// server or proxy"),
+            Error::MissingContact => write!(
+                f,
+                "no contact for the User-Agent, set AOC_CONTACT or contact in aoc.toml to an email address or repository URL"),
+            Error::AlreadyExists(path) => write!(f, "{} already exists, not overwriting it", path.display()),
+        }
+    }
+}
+
+// renders compiler-style, e.g.
+//
+// could not parse range part 'x'
+//  --> input/5/test.txt:1:3
+//   |
+// 1 | 1-x
+//   |   ^
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        let Some(location) = &self.location else {
+            return Ok(());
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+
+        match &self.input_name {
+            Some(name) => write!(f, "\n{}--> {}:{}:{}", gutter, name, location.line, location.column)?,
+            None => write!(f, "\n{}--> line {}, column {}", gutter, location.line, location.column)?,
+        }
+
+        // keep tabs so the caret lines up with the text above it
+        let padding: String = location.text
+            .chars()
+            .take(location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        write!(f, "\n{} |", gutter)?;
+        write!(f, "\n{} | {}", location.line, location.text)?;
+        write!(f, "\n{} | {}{}", gutter, padding, "^".repeat(location.width))
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Self {
+        Error::Io { path: None, source }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Error,
+        Location,
+    };
+
+    #[test]
+    fn locates_span_in_input() {
+        let input = "1-3\n5-x\n";
+        let span = &input[6..7];
+
+        assert_eq!(
+            Location::of(input, span),
+            Some(Location { line: 2, column: 3, text: String::from("5-x"), width: 1 }),
+        );
+    }
+
+    #[test]
+    fn has_no_location_for_span_outside_input() {
+        assert_eq!(Location::of("1-3", "1-3 but elsewhere"), None);
+    }
+
+    #[test]
+    fn renders_caret_under_bad_input() {
+        let input = "1-3\n5-abc\n";
+        let error = Error::parse_at(input, &input[6..9], "could not parse range part 'abc'")
+            .in_input("input/5/test.txt");
+
+        assert_eq!(
+            error.to_string(),
+            "could not parse input: could not parse range part 'abc'
+ --> input/5/test.txt:2:3
+  |
+2 | 5-abc
+  |   ^^^",
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::error::{Error, Result};
+use crate::http;
+use crate::input;
+
+pub const YEAR: u16 = 2025;
+
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const CONTACT_VAR: &str = "AOC_CONTACT";
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".session";
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    // the input was already there so nothing was sent
+    Cached(PathBuf),
+}
+
+// the server to talk to, from AOC_BASE_URL or base_url in aoc.toml. there's
+// no default since the real site needs https, which the client can't speak
+pub fn base_url() -> Result<String> {
+    env::var(BASE_URL_VAR)
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .or_else(|| config::current().base_url.clone())
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .ok_or(Error::MissingBaseUrl)
+}
+
+// the User-Agent, naming whoever runs the client from AOC_CONTACT or contact
+// in aoc.toml. there's no default since it has to reach a real person
+pub fn user_agent() -> Result<String> {
+    env::var(CONTACT_VAR)
+        .ok()
+        .filter(|contact| !contact.trim().is_empty())
+        .or_else(|| config::current().contact.clone())
+        .map(|contact| http::user_agent(contact.trim()))
+        .ok_or(Error::MissingContact)
+}
+
+// the session cookie comes from AOC_SESSION, falling back to the .session
+// file or whichever file session_file in aoc.toml names
+pub fn session_token() -> Result<String> {
+    if let Ok(token) = env::var(SESSION_VAR)
+        && !token.trim().is_empty()
+    {
+        return Ok(token.trim().to_string());
+    }
+
+    match &config::current().session_file {
+        Some(path) => session_token_from(path),
+        None => session_token_from(Path::new(SESSION_FILE)),
+    }
+}
+
+fn session_token_from(path: &Path) -> Result<String> {
+    match fs::read_to_string(path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => Err(Error::MissingSession),
+    }
+}
+
+// who the requests come from, looked up together since every request needs both
+pub struct Identity {
+    pub user_agent: String,
+    pub session: String,
+}
+
+pub fn identity() -> Result<Identity> {
+    Ok(Identity { user_agent: user_agent()?, session: session_token()? })
+}
+
+pub fn fetch(day: u8) -> Result<Fetched> {
+    fetch_to(base_url, identity, day, &input::day_dir(day).join("input.txt"))
+}
+
+// the server, contact and session are only looked up when there's something
+// to download, so cached days work without any of them
+fn fetch_to(
+    base_url: impl FnOnce() -> Result<String>,
+    identity: impl FnOnce() -> Result<Identity>,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let url = format!("{}/{}/day/{}/input", base_url()?, YEAR, day);
+    let identity = identity()?;
+    let cookie = format!("session={}", identity.session);
+
+    let response = http::get(&url, &identity.user_agent, &[("Cookie", &cookie)])?;
+
+    if response.status != 200 {
+        return Err(Error::Http(format!(
+            "{} returned {}: {}",
+            url,
+            response.status,
+            response.body.lines().next().unwrap_or("").trim())));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))?;
+    }
+
+    fs::write(path, &response.body).map_err(|e| Error::io(path, e))?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{
+        Fetched,
+        Identity,
+        fetch_to,
+        session_token_from,
+    };
+    use crate::error::{Error, Result};
+    use crate::http::test::mock_server;
+
+    fn identity() -> Result<Identity> {
+        Ok(Identity { user_agent: String::from("test agent"), session: String::from("abc") })
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn downloads_and_caches_input() {
+        let dir = temp_dir("fetch");
+        let path = dir.join("5").join("input.txt");
+        let (url, server) = mock_server("HTTP/1.1 200 OK\r\nContent-Length: 8\r\n\r\n1-3\n\n4\n");
+
+        let fetched = fetch_to(|| Ok(url.clone()), identity, 5, &path).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1-3\n\n4\n");
+        assert!(request.starts_with("GET /2025/day/5/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nUser-Agent: test agent\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+
+        // nothing is listening any more, so this only passes without a request,
+        // and a cached input doesn't need a server either
+        let fetched = fetch_to(|| Err(Error::MissingBaseUrl), || Err(Error::MissingSession), 5, &path).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(fetched, Fetched::Cached(path));
+    }
+
+    #[test]
+    fn does_not_write_failed_download() {
+        let dir = temp_dir("fetch-failed");
+        let path = dir.join("9").join("input.txt");
+        let (url, server) = mock_server("HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\nnot found");
+
+        let result = fetch_to(|| Ok(url.clone()), identity, 9, &path);
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::Http(_))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn reads_session_token_from_file() {
+        let dir = temp_dir("session");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(".session"), "abc123\n").unwrap();
+
+        let token = session_token_from(&dir.join(".session"));
+        let missing = session_token_from(&dir.join("missing"));
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(token.unwrap(), "abc123");
+        assert!(matches!(missing, Err(Error::MissingSession)));
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::Result;
+use crate::scanner::Scanner;
+
+// (x, y), with (0, 0) in the top left
+pub type Point = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0),           (1, 0),
+    (-1, 1),  (0, 1),  (1, 1),
+];
+
+// a rectangular map stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cells: Vec<T>, width: usize) -> Grid<T> {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "{} cells don't make rows {} wide", cells.len(), width);
+
+        let height = cells.len() / width;
+
+        Grid { cells, width, height }
+    }
+
+    // parses a character map, e.g. "..@\n@@.", where `cell` maps each
+    // character to a cell or returns None if it doesn't belong in the map
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        Scanner::new(input).grid(cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn index_of(&self, (x, y): Point) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    pub fn point_of(&self, index: usize) -> Point {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    // every point, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    // up, right, down and left of the point, where they're inside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&step| self.step(point, step))
+    }
+
+    // every point touching this one, diagonals included
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ALL_DIRECTIONS.iter().filter_map(move |&step| self.step(point, step))
+    }
+
+    fn step(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside a grid {} wide", x, self.width);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // the first point holding `value`, row by row
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(i, _)| self.point_of(i))
+    }
+
+    // draws the grid back out as a character map, handy for debugging
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", point, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+    use crate::error::{Error, Location};
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn parses_with_cell_mapping() {
+        let grid = Grid::parse("..@\n@@.", |c| match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        });
+
+        assert_eq!(grid.unwrap(), Grid::new(vec![false, false, true, true, true, false], 3));
+    }
+
+    #[test]
+    fn points_at_unexpected_character() {
+        let Err(Error::Parse(e)) = Grid::parse("..@\n.x.", |c| (c != 'x').then_some(c)) else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(e.message, "unexpected 'x' in grid");
+        assert_eq!(e.location, Some(Location { line: 2, column: 2, text: String::from(".x."), width: 1 }));
+    }
+
+    #[test]
+    fn rejects_ragged_and_empty_grids() {
+        assert!(Grid::parse("...\n..", Some).is_err());
+        assert!(Grid::parse("", Some).is_err());
+    }
+
+    #[test]
+    fn converts_between_points_and_indices() {
+        let grid = chars("abc\ndef");
+
+        assert_eq!(grid.index_of((1, 1)), Some(4));
+        assert_eq!(grid.index_of((3, 0)), None);
+        assert_eq!(grid.point_of(5), (2, 1));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn gets_neighbours_of_inner_point() {
+        let grid = Grid::new(vec![0; 100], 10);
+
+        let mut result: Vec<_> = grid.neighbours8((1, 1)).collect();
+        result.sort();
+
+        assert_eq!(result, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1), (2, 2)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn doesnt_include_out_of_bounds_neighbours() {
+        let grid = Grid::new(vec![0; 100], 10);
+
+        let mut result: Vec<_> = grid.neighbours8((0, 0)).collect();
+        result.sort();
+
+        assert_eq!(result, vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.neighbours4((9, 9)).collect::<Vec<_>>(), vec![(9, 8), (8, 9)]);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = chars("abc\ndef");
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+    }
+
+    #[test]
+    fn finds_characters() {
+        let grid = chars(".S.\n^.^");
+
+        assert_eq!(grid.find(&'S'), Some((1, 0)));
+        assert_eq!(grid.find(&'#'), None);
+        assert_eq!(grid.find_all(&'^').collect::<Vec<_>>(), vec![(0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn prints_grid() {
+        let grid = Grid::parse("..@\n@@.", |c| Some(c == '@')).unwrap();
+
+        assert_eq!(grid.render(|&roll| if roll { '@' } else { '.' }), "..@\n@@.");
+        assert_eq!(chars("ab\ncd").to_string(), "ab\ncd");
+    }
+}
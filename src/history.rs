@@ -0,0 +1,297 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::input::InputKind;
+use crate::runner::RunResult;
+
+pub const HISTORY_PATH: &str = "bench_history.tsv";
+
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Source {
+    // a single run from the runner
+    Run,
+    // the median of a benchmark
+    Bench,
+    // a benchmark median saved with --save-baseline, what --compare measures against
+    Baseline,
+}
+
+impl Source {
+    fn label(&self) -> &'static str {
+        match self {
+            Source::Run => "run",
+            Source::Bench => "bench",
+            Source::Baseline => "baseline",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Source> {
+        match label {
+            "run" => Some(Source::Run),
+            "bench" => Some(Source::Bench),
+            "baseline" => Some(Source::Baseline),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub revision: String,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub source: Source,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    pub fn new(revision: &str, day: u8, part: u8, input: &InputKind, source: Source, elapsed: Duration) -> Entry {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Entry {
+            timestamp,
+            revision: revision.to_string(),
+            day,
+            part,
+            input: input.label(),
+            source,
+            elapsed,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.revision,
+            self.day,
+            self.part,
+            self.input,
+            self.source.label(),
+            self.elapsed.as_nanos())
+    }
+
+    fn from_line(line: &str) -> Option<Entry> {
+        let fields: Vec<&str> = line.split('\t').collect();
+
+        let [timestamp, revision, day, part, input, source, nanos] = fields.as_slice() else {
+            return None;
+        };
+
+        Some(Entry {
+            timestamp: timestamp.parse().ok()?,
+            revision: revision.to_string(),
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            input: input.to_string(),
+            source: Source::from_label(source)?,
+            elapsed: Duration::from_nanos(nanos.parse().ok()?),
+        })
+    }
+}
+
+// an append-only, tab separated log of timings. it's only read back for
+// --compare, recording just appends to the file
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn load(path: impl AsRef<Path>) -> History {
+        // malformed lines are skipped rather than losing the whole history
+        let entries = fs::read_to_string(path)
+            .map(|text| text.lines().filter_map(Entry::from_line).collect())
+            .unwrap_or_default();
+
+        History { entries }
+    }
+
+    // the most recently saved baseline for this day, part and input. plain
+    // benchmarks never count, so a slow drift across them still gets flagged
+    pub fn baseline(&self, day: u8, part: u8, input: &InputKind) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| {
+                e.day == day
+                && e.part == part
+                && e.input == input.label()
+                && e.source == Source::Baseline
+            })
+    }
+}
+
+fn append(path: impl AsRef<Path>, entries: impl IntoIterator<Item = Entry>) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_line())?;
+    }
+
+    Ok(())
+}
+
+// appends a run entry for each result
+pub fn record_runs<'a>(results: impl IntoIterator<Item = &'a RunResult>) -> io::Result<()> {
+    let revision = current_revision();
+
+    append(
+        HISTORY_PATH,
+        results
+            .into_iter()
+            .map(|result| Entry::new(&revision, result.day, result.part, &result.input, Source::Run, result.total())))
+}
+
+// `source` is either a plain benchmark or one saved as the new baseline
+pub fn record_bench(day: u8, part: u8, input: &InputKind, source: Source, median: Duration) -> io::Result<()> {
+    append(HISTORY_PATH, [Entry::new(&current_revision(), day, part, input, source, median)])
+}
+
+// percentage change from the baseline, positive is slower
+pub fn percent_change(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos() as f64;
+    let current = current.as_nanos() as f64;
+
+    if baseline == 0.0 {
+        return 0.0;
+    }
+
+    (current - baseline) / baseline * 100.0
+}
+
+pub fn is_regression(baseline: Duration, current: Duration, threshold: f64) -> bool {
+    percent_change(baseline, current) > threshold
+}
+
+// short hash of HEAD, marked dirty if there are uncommitted changes
+pub fn current_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    let Some(hash) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return String::from("unknown");
+    };
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+        _ => hash,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::time::Duration;
+
+    use super::{
+        Entry,
+        History,
+        Source,
+        append,
+        is_regression,
+        percent_change,
+    };
+    use crate::input::InputKind;
+
+    #[test]
+    fn round_trips_entry_through_line() {
+        let entry = Entry {
+            timestamp: 1764547200,
+            revision: String::from("361c8dd"),
+            day: 5,
+            part: 2,
+            input: String::from("real"),
+            source: Source::Bench,
+            elapsed: Duration::from_nanos(123456),
+        };
+
+        assert_eq!(Entry::from_line(&entry.to_line()), Some(entry));
+    }
+
+    #[test]
+    fn skips_malformed_line() {
+        assert_eq!(Entry::from_line("1764547200\t361c8dd\t5"), None);
+        assert_eq!(Entry::from_line("1764547200\t361c8dd\t5\t2\treal\tbogus\t100"), None);
+    }
+
+    #[test]
+    fn uses_latest_saved_baseline() {
+        let entry = |revision: &str, input: InputKind, source: Source, micros: u64| {
+            Entry::new(revision, 7, 2, &input, source, Duration::from_micros(micros))
+        };
+
+        let history = History {
+            entries: vec![
+                entry("aaa", InputKind::Real, Source::Baseline, 100),
+                entry("bbb", InputKind::Real, Source::Baseline, 200),
+                entry("ccc", InputKind::Example(1), Source::Baseline, 300),
+                entry("ccc", InputKind::Real, Source::Run, 400),
+                // later benchmarks and compares don't move the baseline
+                entry("ddd", InputKind::Real, Source::Bench, 500),
+            ],
+        };
+
+        let baseline = history.baseline(7, 2, &InputKind::Real).unwrap();
+
+        assert_eq!(baseline.revision, "bbb");
+        assert_eq!(baseline.elapsed, Duration::from_micros(200));
+        assert!(history.baseline(7, 1, &InputKind::Real).is_none());
+
+        let unpinned = History { entries: vec![entry("aaa", InputKind::Real, Source::Bench, 100)] };
+
+        assert!(unpinned.baseline(7, 2, &InputKind::Real).is_none());
+    }
+
+    #[test]
+    fn appends_entries_without_rewriting_the_file() {
+        let path = std::env::temp_dir().join(format!("aoc2025-history-{}", std::process::id()));
+        let entry = |micros: u64| Entry::new("aaa", 7, 2, &InputKind::Real, Source::Baseline, Duration::from_micros(micros));
+
+        fs::write(&path, "not an entry\n").unwrap();
+
+        append(&path, [entry(100)]).unwrap();
+        append(&path, [entry(200)]).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        let history = History::load(&path);
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(text.starts_with("not an entry\n"));
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(history.baseline(7, 2, &InputKind::Real).unwrap().elapsed, Duration::from_micros(200));
+    }
+
+    #[test]
+    fn calculates_percent_change() {
+        assert_eq!(percent_change(Duration::from_micros(100), Duration::from_micros(150)), 50.0);
+        assert_eq!(percent_change(Duration::from_micros(100), Duration::from_micros(75)), -25.0);
+    }
+
+    #[test]
+    fn flags_regression_above_threshold() {
+        let baseline = Duration::from_micros(100);
+
+        assert!(!is_regression(baseline, Duration::from_micros(109), 10.0));
+        assert!(is_regression(baseline, Duration::from_micros(111), 10.0));
+    }
+}
@@ -0,0 +1,261 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+const CLIENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+// the site asks automated tools to say who is running them, so the contact
+// (an email address or repository URL) goes after the client name
+pub fn user_agent(contact: &str) -> String {
+    format!("{} (std TcpStream client; {})", CLIENT, contact)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Url {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+pub fn get(url: &str, user_agent: &str, headers: &[(&str, &str)]) -> Result<Response> {
+    request("GET", url, user_agent, headers, None)
+}
+
+pub fn post_form(url: &str, user_agent: &str, headers: &[(&str, &str)], form: &str) -> Result<Response> {
+    request("POST", url, user_agent, headers, Some(form))
+}
+
+// a bare-bones HTTP/1.1 client, one request per connection
+fn request(method: &str, url: &str, user_agent: &str, headers: &[(&str, &str)], form: Option<&str>) -> Result<Response> {
+    let url = parse_url(url)?;
+
+    let mut stream = TcpStream::connect((url.host.as_str(), url.port))
+        .map_err(|e| Error::Http(format!("could not connect to {}:{}: {}", url.host, url.port, e)))?;
+
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    // the port is part of the Host header unless it's the default one
+    let host = match url.port {
+        80 => url.host.clone(),
+        port => format!("{}:{}", url.host, port),
+    };
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nConnection: close\r\n",
+        method,
+        url.path,
+        host,
+        user_agent);
+
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+
+    if let Some(form) = form {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n\r\n{}", form.len(), form));
+    } else {
+        request.push_str("\r\n");
+    }
+
+    stream.write_all(request.as_bytes())?;
+
+    let mut raw = vec![];
+    stream.read_to_end(&mut raw)?;
+
+    parse_response(&raw)
+}
+
+fn parse_url(url: &str) -> Result<Url> {
+    if url.starts_with("https://") {
+        return Err(Error::Http(format!(
+            "{} uses https, which isn't supported without dependencies, point the base URL at an http:// server or proxy",
+            url)));
+    }
+
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| Error::Http(format!("expected an http:// URL, got '{}'", url)))?;
+
+    let (authority, path) = match rest.find('/') {
+        Some(i) => rest.split_at(i),
+        None => (rest, "/"),
+    };
+
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse()
+                .map_err(|_| Error::Http(format!("invalid port '{}' in '{}'", port, url)))?;
+
+            (host, port)
+        }
+        None => (authority, 80),
+    };
+
+    if host.is_empty() {
+        return Err(Error::Http(format!("no host in '{}'", url)));
+    }
+
+    Ok(Url { host: host.to_string(), port, path: path.to_string() })
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response> {
+    let malformed = || Error::Http(String::from("malformed response from server"));
+
+    let header_end = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(malformed)?;
+
+    let head = String::from_utf8_lossy(&raw[..header_end]);
+    let mut lines = head.lines();
+
+    // e.g. `HTTP/1.1 200 OK`
+    let status: u16 = lines
+        .next()
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(malformed)?;
+
+    let chunked = lines.any(|l| {
+        l.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+
+    let body = &raw[header_end + 4..];
+    let body = if chunked { decode_chunked(body).ok_or_else(malformed)? } else { body.to_vec() };
+
+    Ok(Response { status, body: String::from_utf8_lossy(&body).into_owned() })
+}
+
+// each chunk is `<size in hex>\r\n<data>\r\n`, ending with a zero sized chunk
+fn decode_chunked(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = vec![];
+
+    loop {
+        let line_end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&body[..line_end]).ok()?;
+        // chunk extensions after a `;` are ignored
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+
+        if size == 0 {
+            return Some(decoded);
+        }
+
+        let data = body.get(line_end + 2..line_end + 2 + size)?;
+        decoded.extend_from_slice(data);
+
+        body = body.get(line_end + 4 + size..)?;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::{
+        Response,
+        Url,
+        get,
+        parse_response,
+        parse_url,
+        user_agent,
+    };
+
+    // serves a single canned response on a random local port, the handle
+    // returns the raw request it received
+    pub fn mock_server(response: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+
+            // read until the headers and any body announced by Content-Length are in
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+
+                let text = String::from_utf8_lossy(&request);
+
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let length: usize = text[..header_end]
+                        .lines()
+                        .find_map(|l| l.strip_prefix("Content-Length: "))
+                        .map_or(0, |l| l.parse().unwrap());
+
+                    if request.len() >= header_end + 4 + length {
+                        break;
+                    }
+                }
+
+                if n == 0 {
+                    break;
+                }
+            }
+
+            stream.write_all(response.as_bytes()).unwrap();
+
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn parses_urls() {
+        assert_eq!(
+            parse_url("http://127.0.0.1:8080/2025/day/1/input").unwrap(),
+            Url { host: String::from("127.0.0.1"), port: 8080, path: String::from("/2025/day/1/input") },
+        );
+        assert_eq!(
+            parse_url("http://localhost").unwrap(),
+            Url { host: String::from("localhost"), port: 80, path: String::from("/") },
+        );
+        assert!(parse_url("https://adventofcode.com").is_err());
+        assert!(parse_url("ftp://example.com").is_err());
+    }
+
+    #[test]
+    fn parses_chunked_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1-3\n\r\n3\r\n5-7\r\n0\r\n\r\n";
+
+        assert_eq!(
+            parse_response(raw).unwrap(),
+            Response { status: 200, body: String::from("1-3\n5-7") },
+        );
+    }
+
+    #[test]
+    fn sends_request_to_server() {
+        let (url, server) = mock_server("HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\nnot found");
+
+        let user_agent = user_agent("someone@example.com");
+        let response = get(&format!("{}/some/path", url), &user_agent, &[("Cookie", "session=abc")]).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(response, Response { status: 404, body: String::from("not found") });
+        assert!(request.starts_with("GET /some/path HTTP/1.1\r\n"));
+        assert!(request.contains(&format!("\r\nHost: {}\r\n", url.trim_start_matches("http://"))));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert!(request.contains("\r\nUser-Agent: aoc2025/"));
+        assert!(request.contains(" (std TcpStream client; someone@example.com)\r\n"));
+    }
+}
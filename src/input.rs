@@ -0,0 +1,212 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::error::{Error, Result};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputKind {
+    // example N is read from test.txt for N = 1, otherwise testN.txt
+    Example(u32),
+    Real,
+    // an arbitrary file given with `--input <path>`
+    Path(PathBuf),
+    // `--input -`
+    Stdin,
+}
+
+impl InputKind {
+    pub fn label(&self) -> String {
+        match self {
+            InputKind::Example(1) => String::from("test"),
+            InputKind::Example(n) => format!("test{}", n),
+            InputKind::Real => String::from("real"),
+            InputKind::Path(path) => path.display().to_string(),
+            InputKind::Stdin => String::from("stdin"),
+        }
+    }
+
+    fn file_name(&self) -> Option<String> {
+        match self {
+            InputKind::Example(_) => Some(format!("{}.txt", self.label())),
+            InputKind::Real => Some(String::from("input.txt")),
+            InputKind::Path(_) | InputKind::Stdin => None,
+        }
+    }
+
+    // where the input is read from, stdin has no path
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputKind::Example(_) | InputKind::Real => Some(day_dir(day).join(self.file_name()?)),
+            InputKind::Path(path) => Some(path.clone()),
+            InputKind::Stdin => None,
+        }
+    }
+
+    pub fn from_arg(arg: &str) -> InputKind {
+        if arg == "-" {
+            InputKind::Stdin
+        } else {
+            InputKind::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSelector {
+    One(InputKind),
+    // every example discovered for the day
+    AllExamples,
+}
+
+impl InputSelector {
+    // accepts `real`, `test` (the first example), `test:N` and `test:all`
+    pub fn from_arg(arg: &str) -> Option<InputSelector> {
+        match arg {
+            "real" => Some(InputSelector::One(InputKind::Real)),
+            "test" => Some(InputSelector::One(InputKind::Example(1))),
+            "test:all" => Some(InputSelector::AllExamples),
+            _ => arg
+                .strip_prefix("test:")?
+                .parse()
+                .ok()
+                .filter(|&n| n > 0)
+                .map(|n| InputSelector::One(InputKind::Example(n))),
+        }
+    }
+
+    pub fn resolve(&self, day: u8) -> Vec<InputKind> {
+        match self {
+            InputSelector::One(kind) => vec![kind.clone()],
+            InputSelector::AllExamples => discover_examples(&day_dir(day))
+                .into_iter()
+                .map(InputKind::Example)
+                .collect(),
+        }
+    }
+}
+
+// the directory holding each day's inputs, `input` unless overridden by
+// AOC_INPUT_DIR or input_dir in aoc.toml
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .or_else(|| config::current().input_dir.clone())
+        .unwrap_or_else(|| PathBuf::from("input"))
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    day_dir_in(&input_dir(), day)
+}
+
+fn day_dir_in(base: &Path, day: u8) -> PathBuf {
+    base.join(day.to_string())
+}
+
+// finds test.txt, test2.txt, test3.txt, ... returning their example numbers in order
+fn discover_examples(dir: &Path) -> Vec<u32> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut examples: Vec<u32> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let n = name.strip_prefix("test")?.strip_suffix(".txt")?;
+
+            if n.is_empty() {
+                return Some(1);
+            }
+
+            // test1.txt would clash with test.txt
+            n.parse().ok().filter(|&n| n > 1)
+        })
+        .collect();
+
+    examples.sort_unstable();
+
+    examples
+}
+
+pub fn get_input(day: u8, kind: &InputKind) -> Result<String> {
+    let Some(input_path) = kind.path(day) else {
+        let mut input = String::new();
+
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| Error::io("stdin", e))?;
+
+        return Ok(input);
+    };
+
+    fs::read_to_string(&input_path)
+        .map_err(|e| Error::io(input_path, e))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{
+        InputKind,
+        InputSelector,
+        day_dir_in,
+        discover_examples,
+    };
+
+    #[test]
+    fn builds_day_dir_from_base() {
+        assert_eq!(
+            day_dir_in(Path::new("/tmp/aoc"), 7),
+            PathBuf::from("/tmp/aoc/7"),
+        );
+    }
+
+    #[test]
+    fn parses_input_arg() {
+        assert_eq!(InputKind::from_arg("-"), InputKind::Stdin);
+        assert_eq!(
+            InputKind::from_arg("stress.txt"),
+            InputKind::Path(PathBuf::from("stress.txt")),
+        );
+    }
+
+    #[test]
+    fn parses_input_selector() {
+        assert_eq!(InputSelector::from_arg("real"), Some(InputSelector::One(InputKind::Real)));
+        assert_eq!(InputSelector::from_arg("test"), Some(InputSelector::One(InputKind::Example(1))));
+        assert_eq!(InputSelector::from_arg("test:3"), Some(InputSelector::One(InputKind::Example(3))));
+        assert_eq!(InputSelector::from_arg("test:all"), Some(InputSelector::AllExamples));
+        assert_eq!(InputSelector::from_arg("test:0"), None);
+        assert_eq!(InputSelector::from_arg("tests"), None);
+    }
+
+    #[test]
+    fn discovers_examples_in_order() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-examples-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        for name in ["test10.txt", "test.txt", "test2.txt", "test1.txt", "input.txt", "testing.txt"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let examples = discover_examples(&dir);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(examples, vec![1, 2, 10]);
+    }
+
+    #[test]
+    fn labels_inputs() {
+        assert_eq!(InputKind::Example(1).label(), "test");
+        assert_eq!(InputKind::Example(2).label(), "test2");
+        assert_eq!(InputKind::Real.label(), "real");
+        assert_eq!(InputKind::Path(PathBuf::from("a/b.txt")).label(), "a/b.txt");
+        assert_eq!(InputKind::Stdin.label(), "stdin");
+    }
+}
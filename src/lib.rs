@@ -0,0 +1,20 @@
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+pub mod digits;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod history;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod memo;
+pub mod normalise;
+pub mod range_set;
+pub mod runner;
+pub mod scanner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
@@ -1,57 +1,207 @@
-mod days;
+mod args;
+mod report;
 
 use std::env;
-use std::fs;
-use std::time::Instant;
+use std::io;
+use std::process::ExitCode;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+use aoc2025::error::{Error, Result};
+use aoc2025::history::{self, History, Source};
+use aoc2025::input::{InputKind, InputSelector};
+use aoc2025::submit::{self, Outcome, Submission, Submitted};
+use aoc2025::runner::RunFailure;
+use aoc2025::{answers, bench, config, days, fetch, runner, scaffold};
+use args::{Command, Format};
+use report::Listing;
 
-    if args.len() != 3 && args.len() != 4 {
-        panic!("Usage: aoc2025 <day> <1 / 2> <optional: test>");
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        // verification failed, a benchmark regressed or a day failed
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+
+            if let Error::BadArguments(_) = e {
+                eprintln!("\n{}", args::USAGE);
+            }
+
+            ExitCode::from(e.exit_code())
+        }
     }
+}
 
-    let day: u8 = args[1].parse().expect("day must be a number");
-    let part: u8 = args[2].parse().expect("part must be a number");
+fn run(args: &[String]) -> Result<bool> {
+    let args = args::parse_args(args, config::init()?)?;
+    let format = args.format;
 
-    let use_test_input = args.len() == 4 && args[3] == "test";
+    match args.command {
+        Command::Run { day, part: Some(part), input } => {
+            let results = runner::run_selected(day, part, &input)?;
 
-    let input = get_input(day, use_test_input);
-    let solution_fn = get_solution_fn(day);
-    
-    let start = Instant::now();
-    solution_fn(&input, part);
-    let elapsed = start.elapsed();
+            warn_unrecorded(history::record_runs(&results));
+            report::print_normalisation_warnings(&results);
 
-    println!();
+            for result in &results {
+                report::print_run(result, format);
+            }
+        }
+        Command::Run { day, part: None, input } => {
+            for kind in input.resolve(day) {
+                let results = runner::run_both(day, &kind)?;
 
-    println!(
-        "executed day {} part {} (with {} input) in {} μs",
-        day,
-        part,
-        if use_test_input { "test" } else { "real" },
-        elapsed.as_micros());
-}
+                warn_unrecorded(history::record_runs(&results));
+                report::print_normalisation_warnings(&results);
+
+                report::print_both(&results, format);
+            }
+        }
+        Command::All { inputs } => {
+            let results = runner::run_all(&inputs);
+
+            warn_unrecorded(history::record_runs(results.iter().flatten()));
+            report::print_normalisation_warnings(results.iter().flatten());
+
+            report::print_summary_table(&results, format);
+
+            return Ok(results.iter().all(|r| r.is_ok()));
+        }
+        Command::Verify { inputs } => {
+            let results = runner::run_all(&inputs);
+
+            report::print_normalisation_warnings(results.iter().flatten());
+
+            let mut checked = vec![];
+
+            for result in results {
+                checked.push(match result {
+                    Ok(result) => match answers::load_expected_answers(result.day) {
+                        Ok(expected) => {
+                            let verdict = expected.check(&result.input, result.part, &result.answer);
+
+                            Ok((result, verdict))
+                        }
+                        // a day whose answers can't be read fails on its own like any other
+                        Err(error) => Err(RunFailure { day: result.day, part: result.part, input: result.input, error }),
+                    },
+                    Err(failure) => Err(failure),
+                });
+            }
+
+            report::print_verify_table(&checked, format);
+
+            return Ok(checked.iter().all(|c| c.as_ref().is_ok_and(|(_, v)| !matches!(v, answers::Verdict::Mismatch { .. }))));
+        }
+        Command::Bench { target: Some((day, Some(part))), input, iterations, compare: false, save_baseline, .. } => {
+            for input in input.resolve(day) {
+                report::print_bench_header(day, part, &input, bench::warmup_iterations(iterations), iterations, format);
+
+                let stats = bench::bench(day, part, &input, iterations)?;
+
+                warn_unrecorded(history::record_bench(day, part, &input, bench_source(save_baseline), stats.median));
+
+                report::print_stats(day, part, &input, &stats, format);
+            }
+        }
+        Command::Bench { target, input, iterations, threshold, save_baseline, .. } => {
+            let targets: Vec<(u8, u8)> = match target {
+                Some((day, Some(part))) => vec![(day, part)],
+                Some((day, None)) => vec![(day, 1), (day, 2)],
+                None => days::implemented_days()
+                    .flat_map(|day| [(day, 1), (day, 2)])
+                    .collect(),
+            };
+
+            return compare_benchmarks(&targets, &input, iterations, threshold, save_baseline, format);
+        }
+        Command::Fetch { day } => {
+            let fetched = fetch::fetch(day)?;
 
-fn get_input(day: u8, use_test_input: bool) -> String {
-    let input_file = if use_test_input { "test" } else { "input" };
-    let input_path = format!("input/{}/{}.txt", day, input_file);
+            report::print_fetched(day, &fetched, format);
+        }
+        Command::Submit { day, part } => {
+            let result = runner::run(day, part, &InputKind::Real)?;
+            let submitted = submit::submit(day, part, &result.answer)?;
 
-    let input = fs::read_to_string(&input_path)
-        .unwrap_or_else(|_| panic!("Failed to read input file: {}", input_path));
+            report::print_submitted(day, part, &submitted, format);
 
-    return input;
+            return Ok(matches!(submitted, Submitted::Sent(Submission { outcome: Outcome::Correct, .. })));
+        }
+        Command::New { day } => {
+            let created = scaffold::scaffold(day)?;
+
+            report::print_scaffolded(day, &created, format);
+        }
+        Command::List => {
+            let listings: Vec<Listing> = days::implemented_days()
+                .map(|day| Listing {
+                    day,
+                    examples: InputSelector::AllExamples.resolve(day),
+                    real_input: InputKind::Real.path(day).is_some_and(|p| p.exists()),
+                })
+                .collect();
+
+            report::print_list(&listings, format);
+        }
+    }
+
+    Ok(true)
 }
 
-fn get_solution_fn(day: u8) -> fn(&str, u8) {
-    match day {
-        1 => days::day_01::day_01,
-        2 => days::day_02::day_02,
-        3 => days::day_03::day_03,
-        4 => days::day_04::day_04,
-        5 => days::day_05::day_05,
-        6 => days::day_06::day_06,
-        7 => days::day_07::day_07,
-        _ => panic!("Day {} is not implemented yet", day),
+// history is a nice to have, failing to write it shouldn't fail the run
+fn warn_unrecorded(result: io::Result<()>) {
+    if let Err(e) = result {
+        eprintln!("warning: could not write to {}: {}", history::HISTORY_PATH, e);
     }
-}
\ No newline at end of file
+}
+
+// a benchmark only becomes the baseline when asked to with --save-baseline
+fn bench_source(save_baseline: bool) -> Source {
+    if save_baseline { Source::Baseline } else { Source::Bench }
+}
+
+// benchmarks each target against its saved baseline, returns false if any
+// got slower by more than the threshold or couldn't be benchmarked
+fn compare_benchmarks(
+    targets: &[(u8, u8)],
+    selector: &InputSelector,
+    iterations: u32,
+    threshold: f64,
+    save_baseline: bool,
+    format: Format,
+) -> Result<bool> {
+    // loaded once up front, otherwise a baseline saved this run would be compared against
+    let history = History::load(history::HISTORY_PATH);
+    let mut comparisons = vec![];
+    let mut failures = vec![];
+
+    report::print_comparison_header(iterations, threshold, format);
+
+    for &(day, part) in targets {
+        for input in selector.resolve(day) {
+            let comparison = match bench::compare(&history, day, part, &input, iterations, threshold) {
+                Ok(comparison) => comparison,
+                Err(error) => {
+                    let failure = RunFailure { day, part, input, error };
+
+                    report::print_comparison_failure(&failure, format);
+                    failures.push(failure);
+
+                    continue;
+                }
+            };
+
+            warn_unrecorded(history::record_bench(day, part, &input, bench_source(save_baseline), comparison.median));
+
+            report::print_comparison(&comparison, format);
+
+            comparisons.push(comparison);
+        }
+    }
+
+    report::print_comparison_summary(&comparisons, &failures, format);
+
+    Ok(failures.is_empty() && comparisons.iter().all(|c| !c.regression))
+}
@@ -0,0 +1,181 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+// caches the results of a recursive solver by its state, e.g.
+//
+// fn count(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+//     memo.get_or_insert_with(n, |memo| count(memo, n - 1) + count(memo, n - 2))
+// }
+//
+// a bounded memo forgets its oldest entries once it's full
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    // insertion order, only kept when there's a capacity to enforce
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: Stats,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl Stats {
+    // the share of lookups answered from the cache, 0 before any lookups
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo { cache: HashMap::new(), order: VecDeque::new(), capacity: None, stats: Stats::default() }
+    }
+
+    // keeps at most `capacity` results, evicting the oldest first
+    pub fn bounded(capacity: usize) -> Memo<K, V> {
+        Memo { capacity: Some(capacity), ..Memo::new() }
+    }
+
+    // the cached result for `key`, or else computes it with `compute`, which
+    // gets the memo back so it can recurse
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+
+        let value = compute(self);
+
+        self.insert(key, value.clone());
+
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        let Some(capacity) = self.capacity else {
+            self.cache.insert(key, value);
+            return;
+        };
+
+        if capacity == 0 {
+            return;
+        }
+
+        if self.cache.insert(key.clone(), value).is_some() {
+            return;
+        }
+
+        self.order.push_back(key);
+
+        while self.cache.len() > capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+
+            self.cache.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    // forgets every result, the stats are kept
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Memo,
+        Stats,
+    };
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+
+        memo.get_or_insert_with(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn memoises_recursive_calls() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+        // each of 2..=90 is computed once, and 2..=88 are found again by
+        // the second call of the one two above
+        assert_eq!(memo.stats(), Stats { hits: 87, misses: 89, evictions: 0 });
+        assert_eq!(memo.len(), 89);
+
+        fibonacci(&mut memo, 90);
+
+        assert_eq!(memo.stats().hits, 88);
+    }
+
+    #[test]
+    fn evicts_oldest_entries_when_bounded() {
+        let mut memo = Memo::bounded(2);
+
+        for key in [1, 2, 3] {
+            memo.get_or_insert_with(key, |_| key * 10);
+        }
+
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&1), None);
+        assert_eq!(memo.get(&3), Some(&30));
+        assert_eq!(memo.stats().evictions, 1);
+
+        // a bounded memo still gets the right answer, just with more misses
+        let mut small = Memo::bounded(3);
+
+        assert_eq!(fibonacci(&mut small, 40), 102334155);
+        assert!(small.len() <= 3);
+    }
+
+    #[test]
+    fn reports_hit_rate() {
+        let mut memo: Memo<u8, u8> = Memo::new();
+
+        assert_eq!(memo.stats().hit_rate(), 0.0);
+
+        memo.get_or_insert_with(1, |_| 1);
+        memo.get_or_insert_with(1, |_| 1);
+
+        assert_eq!(memo.stats().hit_rate(), 0.5);
+    }
+}
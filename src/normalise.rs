@@ -0,0 +1,164 @@
+use std::fmt;
+
+// what gets cleaned up before a solver sees its input. days where whitespace
+// matters can turn parts of it off through `Solution::normalisation`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normalise {
+    pub byte_order_mark: bool,
+    pub line_endings: bool,
+    pub trailing_whitespace: bool,
+    pub trailing_newlines: bool,
+}
+
+impl Normalise {
+    pub const ALL: Normalise = Normalise {
+        byte_order_mark: true,
+        line_endings: true,
+        trailing_whitespace: true,
+        trailing_newlines: true,
+    };
+
+    pub const NONE: Normalise = Normalise {
+        byte_order_mark: false,
+        line_endings: false,
+        trailing_whitespace: false,
+        trailing_newlines: false,
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    RemovedByteOrderMark,
+    ConvertedCrlf { lines: usize },
+    TrimmedTrailingWhitespace { lines: usize },
+    // blank lines after the final line, the final newline itself isn't counted
+    RemovedBlankLines { lines: usize },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::RemovedByteOrderMark => write!(f, "removed a byte order mark"),
+            Change::ConvertedCrlf { lines } => write!(f, "converted CRLF line endings on {} line(s)", lines),
+            Change::TrimmedTrailingWhitespace { lines } => write!(f, "trimmed trailing whitespace on {} line(s)", lines),
+            Change::RemovedBlankLines { lines } => write!(f, "removed {} blank line(s) at the end", lines),
+        }
+    }
+}
+
+// returns the cleaned up input and what was changed. a single final newline
+// is expected in any text file so removing it isn't reported as a change
+pub fn normalise(input: &str, options: Normalise) -> (String, Vec<Change>) {
+    let mut changes = vec![];
+    let mut input = input;
+
+    if options.byte_order_mark
+        && let Some(rest) = input.strip_prefix('\u{feff}')
+    {
+        input = rest;
+        changes.push(Change::RemovedByteOrderMark);
+    }
+
+    let mut crlf_lines = 0;
+    let mut trimmed_lines = 0;
+
+    let mut lines: Vec<&str> = input
+        .split('\n')
+        .map(|mut line| {
+            if options.line_endings
+                && let Some(rest) = line.strip_suffix('\r')
+            {
+                line = rest;
+                crlf_lines += 1;
+            }
+
+            if options.trailing_whitespace {
+                let trimmed = line.trim_end_matches([' ', '\t', '\r']);
+
+                if trimmed.len() != line.len() {
+                    trimmed_lines += 1;
+                }
+
+                line = trimmed;
+            }
+
+            line
+        })
+        .collect();
+
+    if crlf_lines > 0 {
+        changes.push(Change::ConvertedCrlf { lines: crlf_lines });
+    }
+
+    if trimmed_lines > 0 {
+        changes.push(Change::TrimmedTrailingWhitespace { lines: trimmed_lines });
+    }
+
+    if options.trailing_newlines {
+        let mut removed = 0;
+
+        while lines.len() > 1 && lines.last() == Some(&"") {
+            lines.pop();
+            removed += 1;
+        }
+
+        // the first one was just the final newline
+        if removed > 1 {
+            changes.push(Change::RemovedBlankLines { lines: removed - 1 });
+        }
+    }
+
+    (lines.join("\n"), changes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Change,
+        Normalise,
+        normalise,
+    };
+
+    #[test]
+    fn leaves_clean_input_alone() {
+        assert_eq!(normalise("1-3\n\n4", Normalise::ALL), (String::from("1-3\n\n4"), vec![]));
+    }
+
+    #[test]
+    fn removes_final_newline_without_reporting_it() {
+        assert_eq!(normalise("11-22,95-115\n", Normalise::ALL), (String::from("11-22,95-115"), vec![]));
+    }
+
+    #[test]
+    fn cleans_up_windows_edited_input() {
+        let (input, changes) = normalise("\u{feff}..@\r\n@@. \r\n\r\n\r\n", Normalise::ALL);
+
+        assert_eq!(input, "..@\n@@.");
+        assert_eq!(
+            changes,
+            vec![
+                Change::RemovedByteOrderMark,
+                Change::ConvertedCrlf { lines: 4 },
+                Change::TrimmedTrailingWhitespace { lines: 1 },
+                Change::RemovedBlankLines { lines: 2 },
+            ],
+        );
+    }
+
+    #[test]
+    fn keeps_trailing_whitespace_when_turned_off() {
+        let options = Normalise { trailing_whitespace: false, ..Normalise::ALL };
+
+        assert_eq!(
+            normalise("123 \r\n 45 \r\n*   \n", options),
+            (String::from("123 \n 45 \n*   "), vec![Change::ConvertedCrlf { lines: 2 }]),
+        );
+    }
+
+    #[test]
+    fn changes_nothing_with_everything_turned_off() {
+        let input = "\u{feff}a \r\n\n\n";
+
+        assert_eq!(normalise(input, Normalise::NONE), (String::from(input), vec![]));
+    }
+}
@@ -0,0 +1,305 @@
+use std::ops::RangeInclusive;
+
+// integers a RangeSet can hold, where stepping to the next and previous value
+// is what lets touching ranges like 1..=2 and 3..=4 merge
+pub trait Discrete: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_next(self) -> Option<Self>;
+    fn checked_prev(self) -> Option<Self>;
+    // how many values are in start..=end
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u128 {
+                    (end - start) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(u32, u64, usize);
+
+// a set of integers stored as sorted, disjoint ranges. overlapping or touching
+// ranges are merged on insert so each value is covered at most once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Discrete> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: Discrete> RangeSet<T> {
+    pub fn new() -> RangeSet<T> {
+        RangeSet { ranges: vec![] }
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+
+        if start > end {
+            return;
+        }
+
+        // the ranges that overlap or touch start..=end, they all get merged into it
+        let first = self.ranges.partition_point(|&(_, e)| e.checked_next().is_some_and(|next| next < start));
+        let last = self.ranges.partition_point(|&(s, _)| end.checked_next().is_none_or(|next| s <= next));
+
+        let merged = match self.ranges[first..last] {
+            [] => (start, end),
+            [(s, _), ..] => (s.min(start), self.ranges[last - 1].1.max(end)),
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    // O(log n) in the number of merged ranges
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    // how many values are covered, a u128 so that all of 0..=u64::MAX fits
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::span(start, end))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // the merged ranges, in order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        // both are sorted so one pass over each is enough
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        self.intersection(&other.complement(T::MIN..=T::MAX))
+    }
+
+    // every value within bounds that isn't in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> RangeSet<T> {
+        let (low, high) = bounds.into_inner();
+        let mut ranges = vec![];
+
+        if low > high {
+            return RangeSet { ranges };
+        }
+
+        // where the next gap starts, None once we're past the upper bound
+        let mut next = Some(low);
+
+        for &(start, end) in &self.ranges {
+            let Some(gap_start) = next else {
+                break;
+            };
+
+            if end < gap_start {
+                continue;
+            }
+
+            if start > high {
+                break;
+            }
+
+            if let Some(gap_end) = start.checked_prev()
+                && gap_start <= gap_end
+            {
+                ranges.push((gap_start, gap_end));
+            }
+
+            next = end.checked_next().filter(|&n| n <= high);
+        }
+
+        if let Some(gap_start) = next {
+            ranges.push((gap_start, high));
+        }
+
+        RangeSet { ranges }
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        // sorting first means every insert lands at the end, so building
+        // from many ranges doesn't shift the whole vec each time
+        let mut ranges: Vec<RangeInclusive<T>> = iter.into_iter().collect();
+        ranges.sort_unstable_by_key(|r| *r.start());
+
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::RangeInclusive;
+
+    use super::RangeSet;
+
+    const SIZE: u64 = 64;
+
+    // a naive set of 0..SIZE to check against
+    fn bitmap(ranges: &[RangeInclusive<u64>]) -> Vec<bool> {
+        (0..SIZE)
+            .map(|v| ranges.iter().any(|r| r.contains(&v)))
+            .collect()
+    }
+
+    fn to_bitmap(set: &RangeSet<u64>) -> Vec<bool> {
+        (0..SIZE).map(|v| set.contains(v)).collect()
+    }
+
+    // deterministic pseudo random ranges within 0..SIZE
+    fn random_ranges(seed: &mut u64, count: usize) -> Vec<RangeInclusive<u64>> {
+        let mut next = || {
+            *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+
+            (*seed >> 33) % SIZE
+        };
+
+        (0..count)
+            .map(|_| {
+                let start = next();
+
+                // an end one before the start makes an empty range, which should be ignored
+                start..=(start + next() % 10).saturating_sub(1).min(SIZE - 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        let set: RangeSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18, 1..=2].into_iter().collect();
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=5, 10..=20]);
+        assert_eq!(set.len(), 16);
+    }
+
+    #[test]
+    fn ignores_empty_ranges() {
+        let mut set = RangeSet::new();
+
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(5u64..=4);
+
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn handles_ranges_at_the_limits() {
+        let set: RangeSet<u64> = [0..=u64::MAX].into_iter().collect();
+
+        assert!(set.contains(0) && set.contains(u64::MAX));
+        assert_eq!(set.len(), u64::MAX as u128 + 1);
+        assert!(set.complement(0..=u64::MAX).is_empty());
+
+        let ends: RangeSet<u64> = [0..=1, u64::MAX - 1..=u64::MAX].into_iter().collect();
+
+        assert_eq!(ends.complement(0..=u64::MAX).iter().collect::<Vec<_>>(), vec![2..=u64::MAX - 2]);
+    }
+
+    #[test]
+    fn complements_within_bounds() {
+        let set: RangeSet<u64> = [3..=5, 10..=12].into_iter().collect();
+
+        assert_eq!(set.complement(0..=15).iter().collect::<Vec<_>>(), vec![0..=2, 6..=9, 13..=15]);
+        assert_eq!(set.complement(4..=11).iter().collect::<Vec<_>>(), vec![6..=9]);
+        assert_eq!(set.complement(6..=9).iter().collect::<Vec<_>>(), vec![6..=9]);
+    }
+
+    #[test]
+    fn matches_naive_bitmap() {
+        let mut seed = 2025;
+
+        for _ in 0..200 {
+            let a_ranges = random_ranges(&mut seed, 6);
+            let b_ranges = random_ranges(&mut seed, 6);
+
+            let a: RangeSet<u64> = a_ranges.iter().cloned().collect();
+            let b: RangeSet<u64> = b_ranges.iter().cloned().collect();
+
+            let a_bits = bitmap(&a_ranges);
+            let b_bits = bitmap(&b_ranges);
+
+            let expected = |f: fn(bool, bool) -> bool| -> Vec<bool> {
+                a_bits.iter().zip(&b_bits).map(|(&x, &y)| f(x, y)).collect()
+            };
+
+            assert_eq!(to_bitmap(&a), a_bits, "{:?}", a_ranges);
+            assert_eq!(a.len(), a_bits.iter().filter(|&&x| x).count() as u128, "{:?}", a_ranges);
+            assert_eq!(to_bitmap(&a.union(&b)), expected(|x, y| x || y));
+            assert_eq!(to_bitmap(&a.intersection(&b)), expected(|x, y| x && y));
+            assert_eq!(to_bitmap(&a.difference(&b)), expected(|x, y| x && !y));
+            assert_eq!(to_bitmap(&a.complement(0..=SIZE - 1)), expected(|x, _| !x));
+
+            // merged ranges never overlap or touch
+            let merged: Vec<_> = a.iter().collect();
+            assert!(merged.windows(2).all(|w| w[0].end() + 1 < *w[1].start()), "{:?}", merged);
+        }
+    }
+}
@@ -0,0 +1,512 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc2025::answers::Verdict;
+use crate::args::Format;
+use aoc2025::bench::{Comparison, Stats};
+use aoc2025::fetch::Fetched;
+use aoc2025::input::InputKind;
+use aoc2025::json::Json;
+use aoc2025::runner::{RunFailure, RunResult};
+use aoc2025::solution::Answer;
+use aoc2025::submit::Submitted;
+
+pub struct Listing {
+    pub day: u8,
+    pub examples: Vec<InputKind>,
+    pub real_input: bool,
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+fn answer_json(answer: &Answer) -> Json {
+    match answer {
+        Answer::Number(n) => (*n).into(),
+        Answer::Text(s) => s.as_str().into(),
+    }
+}
+
+fn run_json(result: &RunResult) -> Json {
+    Json::object([
+        ("day", result.day.into()),
+        ("part", result.part.into()),
+        ("input", result.input.label().into()),
+        ("answer", answer_json(&result.answer)),
+        ("parse_ns", result.parse.as_nanos().into()),
+        ("solve_ns", result.solve.as_nanos().into()),
+    ])
+}
+
+fn failure_json(failure: &RunFailure) -> Json {
+    Json::object([
+        ("day", failure.day.into()),
+        ("part", failure.part.into()),
+        ("input", failure.input.label().into()),
+        ("status", "error".into()),
+        ("error", failure.error.to_string().into()),
+    ])
+}
+
+pub fn print_run(result: &RunResult, format: Format) {
+    if format == Format::Json {
+        println!("{}", run_json(result));
+        return;
+    }
+
+    println!("{}", result.answer);
+
+    println!();
+
+    println!(
+        "executed day {} part {} (with {} input) in {} μs (parse {} μs, solve {} μs)",
+        result.day,
+        result.part,
+        result.input.label(),
+        result.total().as_micros(),
+        result.parse.as_micros(),
+        result.solve.as_micros());
+}
+
+// warns once per day and input about anything normalising the input changed
+pub fn print_normalisation_warnings<'a>(results: impl IntoIterator<Item = &'a RunResult>) {
+    let mut warned: Vec<(u8, &InputKind)> = vec![];
+
+    for result in results {
+        if result.normalised.is_empty() || warned.contains(&(result.day, &result.input)) {
+            continue;
+        }
+
+        warned.push((result.day, &result.input));
+
+        let changes: Vec<String> = result.normalised.iter().map(|c| c.to_string()).collect();
+
+        eprintln!(
+            "warning: day {} {} input was normalised: {}",
+            result.day,
+            result.input.label(),
+            changes.join(", "));
+    }
+}
+
+pub fn print_both([one, two]: &[RunResult; 2], format: Format) {
+    if format == Format::Json {
+        for result in [one, two] {
+            println!("{}", run_json(result).with("shared_parse", true));
+        }
+        return;
+    }
+
+    println!("part 1: {}", one.answer);
+    println!("part 2: {}", two.answer);
+
+    println!();
+
+    println!(
+        "executed day {} both parts (with {} input) in {} μs (parse {} μs once, part 1 {} μs, part 2 {} μs)",
+        one.day,
+        one.input.label(),
+        (one.parse + one.solve + two.solve).as_micros(),
+        one.parse.as_micros(),
+        one.solve.as_micros(),
+        two.solve.as_micros());
+}
+
+// the full errors of whatever failed, printed after a table so it stays lined up
+pub fn print_failures<'a>(failures: impl IntoIterator<Item = &'a RunFailure>) {
+    for failure in failures {
+        eprintln!(
+            "error: day {} part {} ({} input): {}",
+            failure.day,
+            failure.part,
+            failure.input.label(),
+            failure.error);
+    }
+}
+
+pub fn print_summary_table(results: &[Result<RunResult, RunFailure>], format: Format) {
+    if format == Format::Json {
+        for result in results {
+            match result {
+                Ok(result) => println!("{}", run_json(result)),
+                Err(failure) => println!("{}", failure_json(failure)),
+            }
+        }
+        return;
+    }
+
+    let answers: Vec<String> = results
+        .iter()
+        .map(|r| r.as_ref().map_or(String::from("ERROR"), |r| r.answer.to_string()))
+        .collect();
+    let answer_width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max("answer".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<5}  {:<answer_width$}  {:>10}  {:>10}  {:>10}",
+        "day",
+        "part",
+        "input",
+        "answer",
+        "parse (μs)",
+        "solve (μs)",
+        "total (μs)");
+
+    for (result, answer) in results.iter().zip(&answers) {
+        match result {
+            Ok(result) => println!(
+                "{:>3}  {:>4}  {:<5}  {:<answer_width$}  {:>10}  {:>10}  {:>10}",
+                result.day,
+                result.part,
+                result.input.label(),
+                answer,
+                result.parse.as_micros(),
+                result.solve.as_micros(),
+                result.total().as_micros()),
+            Err(failure) => println!(
+                "{:>3}  {:>4}  {:<5}  {:<answer_width$}  {:>10}  {:>10}  {:>10}",
+                failure.day,
+                failure.part,
+                failure.input.label(),
+                answer,
+                "-",
+                "-",
+                "-"),
+        }
+    }
+
+    let ran: Vec<&RunResult> = results.iter().flatten().collect();
+    let parse: Duration = ran.iter().map(|r| r.parse).sum();
+    let solve: Duration = ran.iter().map(|r| r.solve).sum();
+
+    println!();
+    println!(
+        "ran {} solutions in {} μs (parse {} μs, solve {} μs), {} failed",
+        ran.len(),
+        (parse + solve).as_micros(),
+        parse.as_micros(),
+        solve.as_micros(),
+        results.len() - ran.len());
+
+    print_failures(results.iter().filter_map(|r| r.as_ref().err()));
+}
+
+pub fn print_verify_table(checked: &[Result<(RunResult, Verdict), RunFailure>], format: Format) {
+    if format == Format::Json {
+        for checked in checked {
+            let (result, verdict) = match checked {
+                Ok(checked) => checked,
+                Err(failure) => {
+                    println!("{}", failure_json(failure));
+                    continue;
+                }
+            };
+
+            let expected = match verdict {
+                Verdict::Correct => Some(result.answer.to_string()),
+                Verdict::Mismatch { expected } => Some(expected.clone()),
+                Verdict::Unknown => None,
+            };
+
+            let json = run_json(result)
+                .with("expected", expected)
+                .with("status", verdict.label());
+
+            println!("{}", json);
+        }
+        return;
+    }
+
+    println!("{:>3}  {:>4}  {:<5}  {:<8}  answer", "day", "part", "input", "status");
+
+    for checked in checked {
+        let (result, verdict) = match checked {
+            Ok(checked) => checked,
+            Err(failure) => {
+                println!("{:>3}  {:>4}  {:<5}  {:<8}  -", failure.day, failure.part, failure.input.label(), "ERROR");
+                continue;
+            }
+        };
+
+        let note = match verdict {
+            Verdict::Mismatch { expected } => format!(" (expected {})", expected),
+            _ => String::new(),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:<5}  {:<8}  {}{}",
+            result.day,
+            result.part,
+            result.input.label(),
+            verdict.label(),
+            result.answer,
+            note);
+    }
+
+    let count = |f: fn(&Verdict) -> bool| checked.iter().flatten().filter(|(_, v)| f(v)).count();
+    let failures: Vec<&RunFailure> = checked.iter().filter_map(|c| c.as_ref().err()).collect();
+
+    println!();
+    println!(
+        "{} passed, {} mismatched, {} without an expected answer, {} failed",
+        count(|v| *v == Verdict::Correct),
+        count(|v| matches!(v, Verdict::Mismatch { .. })),
+        count(|v| *v == Verdict::Unknown),
+        failures.len());
+
+    print_failures(failures);
+}
+
+pub fn print_bench_header(day: u8, part: u8, input: &InputKind, warmup: u32, iterations: u32, format: Format) {
+    if format == Format::Json {
+        return;
+    }
+
+    println!(
+        "benchmarking day {} part {} (with {} input): {} warmup runs, {} measured runs",
+        day,
+        part,
+        input.label(),
+        warmup,
+        iterations);
+}
+
+pub fn print_stats(day: u8, part: u8, input: &InputKind, stats: &Stats, format: Format) {
+    if format == Format::Json {
+        println!("{}", Json::object([
+            ("day", day.into()),
+            ("part", part.into()),
+            ("input", input.label().into()),
+            ("samples", stats.samples.into()),
+            ("min_ns", stats.min.as_nanos().into()),
+            ("median_ns", stats.median.as_nanos().into()),
+            ("mean_ns", stats.mean.as_nanos().into()),
+            ("p95_ns", stats.p95.as_nanos().into()),
+            ("std_dev_ns", stats.std_dev.as_nanos().into()),
+            ("low_outliers", stats.low_outliers.into()),
+            ("high_outliers", stats.high_outliers.into()),
+        ]));
+        return;
+    }
+
+    let rows = [
+        ("min", stats.min),
+        ("median", stats.median),
+        ("mean", stats.mean),
+        ("p95", stats.p95),
+        ("std dev", stats.std_dev),
+    ];
+
+    println!();
+
+    for (label, duration) in rows {
+        println!("{:<8} {:>12.1} μs", label, micros(duration));
+    }
+
+    let outliers = stats.low_outliers + stats.high_outliers;
+
+    if outliers > 0 {
+        println!();
+        println!(
+            "warning: {} of {} samples are outliers ({} low, {} high)",
+            outliers,
+            stats.samples,
+            stats.low_outliers,
+            stats.high_outliers);
+    }
+}
+
+pub fn print_comparison_header(iterations: u32, threshold: f64, format: Format) {
+    if format == Format::Json {
+        return;
+    }
+
+    println!(
+        "comparing median of {} runs against saved baselines (threshold {}%)",
+        iterations,
+        threshold);
+    println!();
+    println!(
+        "{:>3}  {:>4}  {:<5}  {:<14}  {:>14}  {:>14}  {:>8}  status",
+        "day",
+        "part",
+        "input",
+        "baseline from",
+        "baseline (μs)",
+        "median (μs)",
+        "change");
+}
+
+pub fn print_comparison(c: &Comparison, format: Format) {
+    if format == Format::Json {
+        println!("{}", Json::object([
+            ("day", c.day.into()),
+            ("part", c.part.into()),
+            ("input", c.input.label().into()),
+            ("baseline_ns", c.baseline.map(|b| b.as_nanos()).into()),
+            ("baseline_revision", c.baseline_revision.as_deref().into()),
+            ("median_ns", c.median.as_nanos().into()),
+            ("change_percent", c.change.into()),
+            ("regression", c.regression.into()),
+        ]));
+        return;
+    }
+
+    let status = match (c.baseline, c.regression) {
+        (None, _) => "new",
+        (Some(_), true) => "REGRESSION",
+        (Some(_), false) => "ok",
+    };
+
+    println!(
+        "{:>3}  {:>4}  {:<5}  {:<14}  {:>14}  {:>14.1}  {:>8}  {}",
+        c.day,
+        c.part,
+        c.input.label(),
+        c.baseline_revision.as_deref().unwrap_or("-"),
+        c.baseline.map_or(String::from("-"), |b| format!("{:.1}", micros(b))),
+        micros(c.median),
+        c.change.map_or(String::from("-"), |p| format!("{:+.1}%", p)),
+        status);
+}
+
+pub fn print_comparison_failure(failure: &RunFailure, format: Format) {
+    if format == Format::Json {
+        println!("{}", failure_json(failure));
+        return;
+    }
+
+    println!(
+        "{:>3}  {:>4}  {:<5}  {:<14}  {:>14}  {:>14}  {:>8}  ERROR",
+        failure.day,
+        failure.part,
+        failure.input.label(),
+        "-",
+        "-",
+        "-",
+        "-");
+}
+
+pub fn print_comparison_summary(comparisons: &[Comparison], failures: &[RunFailure], format: Format) {
+    if format == Format::Json {
+        return;
+    }
+
+    println!();
+    println!(
+        "{} of {} benchmarks regressed, {} failed",
+        comparisons.iter().filter(|c| c.regression).count(),
+        comparisons.len(),
+        failures.len());
+
+    if comparisons.iter().any(|c| c.baseline.is_none()) {
+        println!("benchmarks without a baseline are only flagged once one is saved with --save-baseline");
+    }
+
+    print_failures(failures);
+}
+
+pub fn print_fetched(day: u8, fetched: &Fetched, format: Format) {
+    let (path, cached) = match fetched {
+        Fetched::Downloaded(path) => (path, false),
+        Fetched::Cached(path) => (path, true),
+    };
+
+    if format == Format::Json {
+        println!("{}", Json::object([
+            ("day", day.into()),
+            ("path", path.display().to_string().into()),
+            ("cached", cached.into()),
+        ]));
+        return;
+    }
+
+    if cached {
+        println!("{} already exists, not fetching day {} again", path.display(), day);
+    } else {
+        println!("fetched day {} input to {}", day, path.display());
+    }
+}
+
+pub fn print_submitted(day: u8, part: u8, submitted: &Submitted, format: Format) {
+    if format == Format::Json {
+        let json = Json::object([
+            ("day", day.into()),
+            ("part", part.into()),
+        ]);
+
+        let json = match submitted {
+            Submitted::Sent(s) => json
+                .with("answer", s.answer.as_str())
+                .with("outcome", s.outcome.label())
+                .with("cooldown_s", s.cooldown),
+            Submitted::Refused(refusal) => json
+                .with("outcome", "refused")
+                .with("reason", refusal.to_string()),
+        };
+
+        println!("{}", json);
+        return;
+    }
+
+    match submitted {
+        Submitted::Sent(s) => {
+            println!("submitted {} for day {} part {}: {}", s.answer, day, part, s.outcome.label().replace('_', " "));
+
+            if s.cooldown > 0 {
+                println!("wait {}s before submitting again", s.cooldown);
+            }
+        }
+        Submitted::Refused(refusal) => println!("not submitting day {} part {}: {}", day, part, refusal),
+    }
+}
+
+pub fn print_scaffolded(day: u8, created: &[PathBuf], format: Format) {
+    let created: Vec<String> = created
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect();
+
+    if format == Format::Json {
+        println!("{}", Json::object([
+            ("day", day.into()),
+            ("created", created.into()),
+        ]));
+        return;
+    }
+
+    for path in &created {
+        println!("created {}", path);
+    }
+
+    println!("day {} will be picked up on the next build", day);
+}
+
+pub fn print_list(listings: &[Listing], format: Format) {
+    let examples = |l: &Listing| -> Vec<String> {
+        l.examples.iter().map(|e| e.label()).collect()
+    };
+
+    if format == Format::Json {
+        for listing in listings {
+            println!("{}", Json::object([
+                ("day", listing.day.into()),
+                ("parts", vec![1u8, 2].into()),
+                ("examples", examples(listing).into()),
+                ("real_input", listing.real_input.into()),
+            ]));
+        }
+        return;
+    }
+
+    println!("{:>3}  {:<5}  {:<10}  examples", "day", "parts", "real input");
+
+    for listing in listings {
+        println!(
+            "{:>3}  {:<5}  {:<10}  {}",
+            listing.day,
+            "1, 2",
+            if listing.real_input { "yes" } else { "missing" },
+            examples(listing).join(", "));
+    }
+}
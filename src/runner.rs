@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use crate::days;
+use crate::error::{Error, Result};
+use crate::input::{self, InputKind, InputSelector};
+use crate::normalise::{self, Change};
+use crate::solution::{Answer, DynSolution};
+
+pub struct RunResult {
+    pub day: u8,
+    pub part: u8,
+    pub input: InputKind,
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+    // what normalising the input changed before the solver saw it
+    pub normalised: Vec<Change>,
+}
+
+// a day, part and input that couldn't be run, e.g. a freshly scaffolded day
+// or one without its input yet
+#[derive(Debug)]
+pub struct RunFailure {
+    pub day: u8,
+    pub part: u8,
+    pub input: InputKind,
+    pub error: Error,
+}
+
+impl RunResult {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+// looks up the day and reads its input, normalised the way the day wants it
+pub fn prepare(day: u8, kind: &InputKind) -> Result<(&'static dyn DynSolution, String, Vec<Change>)> {
+    let solution = days::get_solution(day).ok_or(Error::UnknownDay(day))?;
+    let (input, changes) = normalise::normalise(&input::get_input(day, kind)?, solution.normalisation());
+
+    Ok((solution, input, changes))
+}
+
+pub fn run(day: u8, part: u8, kind: &InputKind) -> Result<RunResult> {
+    let (solution, input, normalised) = prepare(day, kind)?;
+
+    let timed = solution
+        .run(&input, part)
+        .map_err(|e| e.in_input(input_name(day, kind)))?;
+
+    Ok(RunResult {
+        day,
+        part,
+        input: kind.clone(),
+        answer: timed.answer,
+        parse: timed.parse,
+        solve: timed.solve,
+        normalised,
+    })
+}
+
+// how an input is named in parse errors
+pub fn input_name(day: u8, kind: &InputKind) -> String {
+    kind.path(day)
+        .map_or_else(|| kind.label(), |path| path.display().to_string())
+}
+
+// parses once and solves both parts, each result carries the shared parse time
+pub fn run_both(day: u8, kind: &InputKind) -> Result<[RunResult; 2]> {
+    let (solution, input, normalised) = prepare(day, kind)?;
+
+    let timed = solution
+        .run_both(&input)
+        .map_err(|e| e.in_input(input_name(day, kind)))?;
+
+    let [(answer_one, solve_one), (answer_two, solve_two)] = timed.parts;
+
+    let result = |part, answer, solve| RunResult {
+        day,
+        part,
+        input: kind.clone(),
+        answer,
+        parse: timed.parse,
+        solve,
+        normalised: normalised.clone(),
+    };
+
+    Ok([result(1, answer_one, solve_one), result(2, answer_two, solve_two)])
+}
+
+pub fn run_selected(day: u8, part: u8, selector: &InputSelector) -> Result<Vec<RunResult>> {
+    selector
+        .resolve(day)
+        .iter()
+        .map(|kind| run(day, part, kind))
+        .collect()
+}
+
+// runs every day, part and input, a failing one is reported alongside the
+// rest instead of stopping them
+pub fn run_all(selectors: &[InputSelector]) -> Vec<std::result::Result<RunResult, RunFailure>> {
+    let mut results = vec![];
+
+    for day in days::implemented_days() {
+        for selector in selectors {
+            for kind in selector.resolve(day) {
+                for part in [1, 2] {
+                    results.push(run(day, part, &kind).map_err(|error| RunFailure { day, part, input: kind.clone(), error }));
+                }
+            }
+        }
+    }
+
+    results
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::input;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+const DAYS_DIR: &str = "src/days";
+
+// returns the files it created, build.rs picks the new day up so nothing needs registering
+pub fn scaffold(day: u8) -> Result<Vec<PathBuf>> {
+    scaffold_in(Path::new(DAYS_DIR), &input::day_dir(day), day)
+}
+
+fn scaffold_in(days_dir: &Path, input_dir: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::BadArguments(format!("day must be between 1 and 25, got {}", day)));
+    }
+
+    let module_path = days_dir.join(format!("day_{:02}.rs", day));
+
+    if module_path.exists() {
+        return Err(Error::AlreadyExists(module_path));
+    }
+
+    let mut created = vec![];
+
+    fs::write(&module_path, render(day)).map_err(|e| Error::io(&module_path, e))?;
+    created.push(module_path);
+
+    fs::create_dir_all(input_dir).map_err(|e| Error::io(input_dir, e))?;
+
+    // an empty example to paste the puzzle's example into, unless there already is one
+    let test_path = input_dir.join("test.txt");
+    if !test_path.exists() {
+        fs::write(&test_path, "").map_err(|e| Error::io(&test_path, e))?;
+        created.push(test_path);
+    }
+
+    Ok(created)
+}
+
+fn render(day: u8) -> String {
+    TEMPLATE
+        .replace("{{DD}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{
+        render,
+        scaffold_in,
+    };
+    use crate::error::Error;
+
+    #[test]
+    fn renders_template_for_day() {
+        let module = render(8);
+
+        assert!(module.contains("pub struct Day08;"));
+        assert!(module.contains("day 8 part 1"));
+        assert!(module.contains("#[cfg(test)]\nmod test {"));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn scaffolds_day_and_refuses_to_overwrite_it() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-scaffold-{}", std::process::id()));
+        let days_dir = dir.join("days");
+        let input_dir = dir.join("input").join("2");
+        fs::create_dir_all(&days_dir).unwrap();
+
+        let scaffolded = scaffold_in(&days_dir, &input_dir, 2);
+        let module = fs::read_to_string(days_dir.join("day_02.rs"));
+        let again = scaffold_in(&days_dir, &input_dir, 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(scaffolded.unwrap(), vec![days_dir.join("day_02.rs"), input_dir.join("test.txt")]);
+        assert_eq!(module.unwrap(), render(2));
+        assert!(matches!(again, Err(Error::AlreadyExists(_))));
+    }
+}
@@ -0,0 +1,383 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+
+// reads puzzle input from the front, a piece at a time. every error points
+// at where in the whole input it went wrong, even from a scanner over a
+// single line or section
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Scanner<'a> {
+        Scanner { input, rest: input }
+    }
+
+    // what hasn't been read yet
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    // `span` has to be a slice of the input for the error to have a location
+    pub fn error_at(&self, span: &str, message: impl Into<String>) -> Error {
+        Error::parse_at(self.input, span, message)
+    }
+
+    // an error pointing at the next char, or just past the end if there isn't one
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        let next = self.rest.chars().next().map_or(0, char::len_utf8);
+
+        self.error_at(&self.rest[..next], message)
+    }
+
+    fn found(&self) -> String {
+        match self.rest.chars().next() {
+            Some(c) => format!("'{}'", c),
+            None => String::from("nothing"),
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+
+        self.rest = rest;
+
+        taken
+    }
+
+    // everything up to the end, leaving an empty scanner that still knows
+    // where it is for errors
+    fn take_rest(&mut self) -> &'a str {
+        self.advance(self.rest.len())
+    }
+
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T> {
+        let len = self.rest.bytes().take_while(u8::is_ascii_digit).count();
+
+        self.number(len)
+    }
+
+    // like `unsigned` but with an optional leading `-` or `+`
+    pub fn signed<T: FromStr>(&mut self) -> Result<T> {
+        let sign = usize::from(self.rest.starts_with(['-', '+']));
+        let len = self.rest[sign..].bytes().take_while(u8::is_ascii_digit).count();
+
+        if len == 0 {
+            return Err(self.error(format!("expected a number, found {}", self.found())));
+        }
+
+        self.number(sign + len)
+    }
+
+    fn number<T: FromStr>(&mut self, len: usize) -> Result<T> {
+        if len == 0 {
+            return Err(self.error(format!("expected a number, found {}", self.found())));
+        }
+
+        let digits = &self.rest[..len];
+        let value = digits
+            .parse()
+            .map_err(|_| self.error_at(digits, format!("number {} is out of range", digits)))?;
+
+        self.advance(len);
+
+        Ok(value)
+    }
+
+    // a run of single digit values, e.g. 987 is [9, 8, 7]
+    pub fn digits(&mut self) -> Result<Vec<u8>> {
+        let len = self.rest.bytes().take_while(u8::is_ascii_digit).count();
+
+        if len == 0 {
+            return Err(self.error(format!("expected a digit, found {}", self.found())));
+        }
+
+        Ok(self.advance(len).bytes().map(|b| b - b'0').collect())
+    }
+
+    // `start-end`, e.g. 11-22
+    pub fn unsigned_range<T: FromStr>(&mut self) -> Result<RangeInclusive<T>> {
+        let start = self.unsigned()?;
+        self.literal("-")?;
+        let end = self.unsigned()?;
+
+        Ok(start..=end)
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        if !self.eat(literal) {
+            return Err(self.error(format!("expected '{}', found {}", literal, self.found())));
+        }
+
+        Ok(())
+    }
+
+    // reads `literal` if it's next, returning whether it was
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if !self.rest.starts_with(literal) {
+            return false;
+        }
+
+        self.advance(literal.len());
+
+        true
+    }
+
+    // reads whichever option comes next, e.g. [("L", -1), ("R", 1)]
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T> {
+        if let Some(&(_, value)) = options.iter().find(|(literal, _)| self.eat(literal)) {
+            return Ok(value);
+        }
+
+        let expected: Vec<String> = options
+            .iter()
+            .map(|(literal, _)| format!("'{}'", literal))
+            .collect();
+
+        Err(self.error(format!("expected one of {}, found {}", expected.join(", "), self.found())))
+    }
+
+    pub fn skip_spaces(&mut self) {
+        let len = self.rest.len() - self.rest.trim_start_matches(' ').len();
+
+        self.advance(len);
+    }
+
+    // fails if anything is left over
+    pub fn end(&self) -> Result<()> {
+        if !self.rest.is_empty() {
+            return Err(self.error_at(self.rest, format!("unexpected '{}'", self.rest.lines().next().unwrap_or(self.rest))));
+        }
+
+        Ok(())
+    }
+
+    // one or more items with `separator` between them
+    pub fn separated<T>(&mut self, separator: &str, mut item: impl FnMut(&mut Scanner<'a>) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    // splits off every remaining line as its own scanner
+    pub fn lines(&mut self) -> impl Iterator<Item = Scanner<'a>> + use<'a> {
+        let input = self.input;
+
+        self.take_rest()
+            .lines()
+            .map(move |line| Scanner { input, rest: line })
+    }
+
+    // reads each remaining line with `line`, which has to read all of it
+    pub fn each_line<T>(&mut self, mut line: impl FnMut(&mut Scanner<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.lines()
+            .map(|mut scanner| {
+                let value = line(&mut scanner)?;
+                scanner.end()?;
+
+                Ok(value)
+            })
+            .collect()
+    }
+
+    // splits off every remaining blank line separated section
+    pub fn sections(&mut self) -> Vec<Scanner<'a>> {
+        let input = self.input;
+
+        self.take_rest()
+            .split("\n\n")
+            .map(|section| Scanner { input, rest: section })
+            .collect()
+    }
+
+    // splits the rest into columns of single character cells, each read top to
+    // bottom. short lines are padded with empty cells at their end, so every
+    // cell still points somewhere in the input for errors
+    pub fn columns(&mut self) -> Vec<Vec<&'a str>> {
+        let rows: Vec<Vec<&'a str>> = self
+            .lines()
+            .map(|line| {
+                let line = line.rest;
+                let mut cells: Vec<&'a str> = line
+                    .char_indices()
+                    .map(|(i, c)| &line[i..i + c.len_utf8()])
+                    .collect();
+
+                cells.push(&line[line.len()..]);
+
+                cells
+            })
+            .collect();
+
+        // every row got an extra empty cell, which doesn't count towards the width
+        let width = rows.iter().map(Vec::len).max().map_or(0, |len| len - 1);
+
+        (0..width)
+            .map(|x| rows.iter().map(|row| row[x.min(row.len() - 1)]).collect())
+            .collect()
+    }
+
+    // reads the rest as a character map where `cell` maps each character to a
+    // cell, or returns None if it doesn't belong in the map
+    pub fn grid<T>(&mut self, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let first_line = self
+            .rest
+            .lines()
+            .next()
+            .filter(|l| !l.is_empty())
+            .ok_or_else(|| Error::parse("grid is empty"))?;
+
+        let width = first_line.chars().count();
+        let mut cells = vec![];
+
+        for line in self.lines() {
+            let line = line.rest;
+            let line_width = line.chars().count();
+
+            if line_width != width {
+                return Err(self.error_at(line, format!("all rows must be {} wide, got {}", width, line_width)));
+            }
+
+            for (i, c) in line.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| self.error_at(&line[i..i + c.len_utf8()], format!("unexpected '{}' in grid", c)))?;
+
+                cells.push(value);
+            }
+        }
+
+        Ok(Grid::new(cells, width))
+    }
+
+    pub fn digit_grid(&mut self) -> Result<Grid<u8>> {
+        self.grid(|c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    pub fn char_grid(&mut self) -> Result<Grid<char>> {
+        self.grid(Some)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Scanner;
+    use crate::error::{Error, Location};
+    use crate::grid::Grid;
+
+    fn location(result: Result<impl std::fmt::Debug, Error>) -> (String, Location) {
+        match result {
+            Err(Error::Parse(e)) => (e.message, e.location.expect("error should have a location")),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reads_numbers_and_literals() {
+        let mut scanner = Scanner::new("L68,-12+5");
+
+        assert_eq!(scanner.one_of(&[("L", -1), ("R", 1)]).unwrap(), -1);
+        assert_eq!(scanner.unsigned::<u32>().unwrap(), 68);
+        assert!(scanner.eat(","));
+        assert_eq!(scanner.signed::<i64>().unwrap(), -12);
+        assert_eq!(scanner.signed::<i64>().unwrap(), 5);
+        assert!(scanner.end().is_ok());
+    }
+
+    #[test]
+    fn reads_separated_ranges() {
+        let mut scanner = Scanner::new("11-22,95-115");
+
+        let ranges = scanner.separated(",", |s| s.unsigned_range::<u64>()).unwrap();
+
+        assert_eq!(ranges, vec![11..=22, 95..=115]);
+    }
+
+    #[test]
+    fn points_at_errors_within_a_line() {
+        let mut scanner = Scanner::new("1-3\n5-x7");
+        let mut lines = scanner.lines().skip(1);
+
+        assert_eq!(
+            location(lines.next().unwrap().unsigned_range::<u64>()),
+            (String::from("expected a number, found 'x'"), Location { line: 2, column: 3, text: String::from("5-x7"), width: 1 }),
+        );
+    }
+
+    #[test]
+    fn points_at_out_of_range_numbers() {
+        assert_eq!(
+            location(Scanner::new("1 300").each_line(|s| {
+                s.unsigned::<u8>()?;
+                s.literal(" ")?;
+                s.unsigned::<u8>()
+            })),
+            (String::from("number 300 is out of range"), Location { line: 1, column: 3, text: String::from("1 300"), width: 3 }),
+        );
+    }
+
+    #[test]
+    fn rejects_leftovers_and_missing_input() {
+        assert_eq!(location(Scanner::new("12ab").each_line(|s| s.unsigned::<u32>())).0, "unexpected 'ab'");
+        assert_eq!(location(Scanner::new("").unsigned::<u32>()).0, "expected a number, found nothing");
+        assert_eq!(location(Scanner::new("-").signed::<i32>()).0, "expected a number, found '-'");
+        assert_eq!(location(Scanner::new("U5").one_of(&[("L", -1), ("R", 1)])).0, "expected one of 'L', 'R', found 'U'");
+    }
+
+    #[test]
+    fn splits_blank_line_sections() {
+        let mut scanner = Scanner::new("1-3\n5-7\n\n4\n5");
+
+        let sections: Vec<&str> = scanner.sections().iter().map(|s| s.rest()).collect();
+
+        assert_eq!(sections, vec!["1-3\n5-7", "4\n5"]);
+        assert!(scanner.is_empty());
+    }
+
+    #[test]
+    fn reads_grids_from_a_section() {
+        let mut sections = Scanner::new("header\n\n12\n34").sections();
+
+        assert_eq!(sections[1].digit_grid().unwrap(), Grid::new(vec![1, 2, 3, 4], 2));
+        assert_eq!(
+            location(Scanner::new("header\n\n12\n3x").sections()[1].digit_grid()),
+            (String::from("unexpected 'x' in grid"), Location { line: 4, column: 2, text: String::from("3x"), width: 1 }),
+        );
+        assert_eq!(Scanner::new("ab\ncd").char_grid().unwrap().to_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn reads_digits() {
+        assert_eq!(Scanner::new("987").each_line(|s| s.digits()).unwrap(), vec![vec![9, 8, 7]]);
+        assert_eq!(location(Scanner::new("98x").each_line(|s| s.digits())).0, "unexpected 'x'");
+        assert_eq!(location(Scanner::new("x").digits()).0, "expected a digit, found 'x'");
+    }
+
+    #[test]
+    fn reads_columns_padding_short_lines() {
+        let mut scanner = Scanner::new("ab\nc\ndef");
+
+        let columns = scanner.columns();
+
+        assert_eq!(columns, vec![vec!["a", "c", "d"], vec!["b", "", "e"], vec!["", "", "f"]]);
+        assert!(scanner.is_empty());
+
+        // a padded cell sits at the end of its line
+        assert_eq!(
+            location(Err::<(), _>(scanner.error_at(columns[1][1], "missing"))).1,
+            Location { line: 2, column: 2, text: String::from("c"), width: 1 },
+        );
+    }
+}
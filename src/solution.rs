@@ -0,0 +1,134 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::normalise::Normalise;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n as u64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+
+    // how the runner cleans up the input first, override to keep whitespace that matters
+    fn normalisation(&self) -> Normalise {
+        Normalise::ALL
+    }
+
+    fn solve(&self, input: &Self::Input, part: u8) -> Result<Answer> {
+        match part {
+            1 => self.part_one(input),
+            2 => self.part_two(input),
+            _ => Err(Error::BadArguments(format!("part must be 1 or 2, got {}", part))),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+// both parts solved from a single parse
+#[derive(Debug)]
+pub struct TimedBoth {
+    pub parse: Duration,
+    pub parts: [(Answer, Duration); 2],
+}
+
+// `Solution` has an associated input type so can't be used as a trait object,
+// this erases it so the runner can treat every day the same
+pub trait DynSolution {
+    fn run(&self, input: &str, part: u8) -> Result<Timed>;
+    fn run_both(&self, input: &str) -> Result<TimedBoth>;
+    fn normalisation(&self) -> Normalise;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn run(&self, input: &str, part: u8) -> Result<Timed> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = self.solve(&parsed, part)?;
+        let solve = start.elapsed();
+
+        Ok(Timed {
+            answer,
+            parse,
+            solve,
+        })
+    }
+
+    fn run_both(&self, input: &str) -> Result<TimedBoth> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let solve = |part| -> Result<(Answer, Duration)> {
+            let start = Instant::now();
+            let answer = self.solve(&parsed, part)?;
+
+            Ok((answer, start.elapsed()))
+        };
+
+        Ok(TimedBoth {
+            parse,
+            parts: [solve(1)?, solve(2)?],
+        })
+    }
+
+    fn normalisation(&self) -> Normalise {
+        Solution::normalisation(self)
+    }
+}
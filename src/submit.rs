@@ -0,0 +1,428 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{Error, Result};
+use crate::fetch::{self, Identity, YEAR};
+use crate::http;
+use crate::input;
+use crate::solution::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // submitted during a cooldown, the answer wasn't checked
+    Wait,
+    // the part was already solved on the site
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wait => "wait",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Outcome> {
+        [
+            Outcome::Correct,
+            Outcome::Wrong,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wait,
+            Outcome::AlreadySolved,
+            Outcome::Unknown,
+        ]
+        .into_iter()
+        .find(|o| o.label() == label)
+    }
+
+    // whether the server actually judged the answer
+    fn was_checked(&self) -> bool {
+        matches!(self, Outcome::Correct | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    // seconds the server asked us to wait before the next submission
+    pub cooldown: u64,
+}
+
+impl Submission {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.part,
+            self.outcome.label(),
+            self.cooldown,
+            self.answer)
+    }
+
+    fn from_line(line: &str) -> Option<Submission> {
+        // the answer goes last since text answers could contain anything but a newline
+        let mut fields = line.splitn(5, '\t');
+
+        Some(Submission {
+            timestamp: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            outcome: Outcome::from_label(fields.next()?)?,
+            cooldown: fields.next()?.parse().ok()?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadyTried { outcome: Outcome },
+    // a numeric answer on the wrong side of one already known to be too high or low
+    OutOfBounds { bound: u64, outcome: Outcome },
+    CoolingDown { seconds_left: u64 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "this part was already solved with {}", answer),
+            Refusal::AlreadyTried { outcome } => write!(f, "this answer was already submitted and was {}", outcome.label()),
+            Refusal::OutOfBounds { bound, outcome: Outcome::TooHigh } => write!(f, "{} was already too high", bound),
+            Refusal::OutOfBounds { bound, .. } => write!(f, "{} was already too low", bound),
+            Refusal::CoolingDown { seconds_left } => write!(f, "the server asked us to wait another {}s", seconds_left),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Submitted {
+    Sent(Submission),
+    // nothing was sent, the local record says it would be pointless
+    Refused(Refusal),
+}
+
+// every submission for a day, kept in <input dir>/<day>/submissions
+pub struct Submissions {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn load(path: impl AsRef<Path>) -> Submissions {
+        let path = path.as_ref().to_path_buf();
+
+        let entries = fs::read_to_string(&path)
+            .map(|text| text.lines().filter_map(Submission::from_line).collect())
+            .unwrap_or_default();
+
+        Submissions { path, entries }
+    }
+
+    pub fn record(&mut self, submission: Submission) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| Error::io(&self.path, e))?;
+
+        writeln!(file, "{}", submission.to_line()).map_err(|e| Error::io(&self.path, e))?;
+
+        self.entries.push(submission);
+
+        Ok(())
+    }
+
+    // why this answer shouldn't be sent, if there's a reason
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Option<Refusal> {
+        // the cooldown applies across both parts
+        if let Some(last) = self.entries.last() {
+            let ready_at = last.timestamp + last.cooldown;
+
+            if now < ready_at {
+                return Some(Refusal::CoolingDown { seconds_left: ready_at - now });
+            }
+        }
+
+        let checked: Vec<&Submission> = self.entries
+            .iter()
+            .filter(|s| s.part == part && s.outcome.was_checked())
+            .collect();
+
+        if let Some(correct) = checked.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Some(Refusal::AlreadySolved { answer: correct.answer.clone() });
+        }
+
+        if let Some(tried) = checked.iter().find(|s| s.answer == answer) {
+            return Some(Refusal::AlreadyTried { outcome: tried.outcome });
+        }
+
+        let number: u64 = answer.parse().ok()?;
+
+        let bound = |outcome| checked
+            .iter()
+            .filter(move |s| s.outcome == outcome)
+            .filter_map(|s| s.answer.parse::<u64>().ok());
+
+        if let Some(bound) = bound(Outcome::TooHigh).min().filter(|&b| number >= b) {
+            return Some(Refusal::OutOfBounds { bound, outcome: Outcome::TooHigh });
+        }
+
+        if let Some(bound) = bound(Outcome::TooLow).max().filter(|&b| number <= b) {
+            return Some(Refusal::OutOfBounds { bound, outcome: Outcome::TooLow });
+        }
+
+        None
+    }
+}
+
+pub fn submit(day: u8, part: u8, answer: &Answer) -> Result<Submitted> {
+    let mut submissions = Submissions::load(input::day_dir(day).join("submissions"));
+
+    submit_to(fetch::base_url, fetch::identity, &mut submissions, day, part, answer, now())
+}
+
+fn submit_to(
+    base_url: impl FnOnce() -> Result<String>,
+    identity: impl FnOnce() -> Result<Identity>,
+    submissions: &mut Submissions,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    now: u64,
+) -> Result<Submitted> {
+    let answer = answer.to_string();
+
+    if let Some(refusal) = submissions.check(part, &answer, now) {
+        return Ok(Submitted::Refused(refusal));
+    }
+
+    let url = format!("{}/{}/day/{}/answer", base_url()?, YEAR, day);
+    let identity = identity()?;
+    let cookie = format!("session={}", identity.session);
+    let form = format!("level={}&answer={}", part, form_encode(&answer));
+
+    let response = http::post_form(&url, &identity.user_agent, &[("Cookie", &cookie)], &form)?;
+
+    if response.status != 200 {
+        return Err(Error::Http(format!("{} returned {}", url, response.status)));
+    }
+
+    let (outcome, cooldown) = parse_outcome(&response.body);
+    let submission = Submission { timestamp: now, part, answer, outcome, cooldown };
+
+    submissions.record(submission.clone())?;
+
+    Ok(Submitted::Sent(submission))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// reads the verdict and any requested wait out of the response page
+fn parse_outcome(body: &str) -> (Outcome, u64) {
+    let outcome = if body.contains("That's the right answer") {
+        Outcome::Correct
+    } else if body.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if body.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if body.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if body.contains("You gave an answer too recently") {
+        Outcome::Wait
+    } else if body.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown
+    };
+
+    (outcome, parse_cooldown(body).unwrap_or(0))
+}
+
+// either `You have 1m 23s left to wait` or `Please wait 5 minutes before trying again`
+fn parse_cooldown(body: &str) -> Option<u64> {
+    if let Some(end) = body.find(" left to wait") {
+        let start = body[..end].rfind("You have ")? + "You have ".len();
+
+        return body[start..end]
+            .split_whitespace()
+            .map(|part| {
+                let (n, unit) = part.split_at_checked(part.len().checked_sub(1)?)?;
+                let n: u64 = n.parse().ok()?;
+
+                match unit {
+                    "h" => Some(n * 3600),
+                    "m" => Some(n * 60),
+                    "s" => Some(n),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    let start = body.find("lease wait ")? + "lease wait ".len();
+    let end = start + body[start..].find(" before trying again")?;
+
+    match body[start..end].split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["one", "minute"] => Some(60),
+        [n, "minutes"] => Some(n.parse::<u64>().ok()? * 60),
+        [n, "seconds"] => n.parse().ok(),
+        _ => None,
+    }
+}
+
+// percent-encodes everything but unreserved characters
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{
+        Outcome,
+        Refusal,
+        Submission,
+        Submissions,
+        Submitted,
+        form_encode,
+        parse_outcome,
+        submit_to,
+    };
+    use crate::error::Result;
+    use crate::fetch::Identity;
+    use crate::http::test::mock_server;
+    use crate::solution::Answer;
+
+    fn identity() -> Result<Identity> {
+        Ok(Identity { user_agent: String::from("test agent"), session: String::from("abc") })
+    }
+
+    fn submission(timestamp: u64, part: u8, answer: &str, outcome: Outcome, cooldown: u64) -> Submission {
+        Submission { timestamp, part, answer: answer.to_string(), outcome, cooldown }
+    }
+
+    fn submissions(entries: Vec<Submission>) -> Submissions {
+        Submissions { path: Default::default(), entries }
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(parse_outcome("<p>That's the right answer!  You are one gold star closer.</p>"), (Outcome::Correct, 0));
+        assert_eq!(
+            parse_outcome("<p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p>"),
+            (Outcome::TooHigh, 60),
+        );
+        assert_eq!(
+            parse_outcome("<p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p>"),
+            (Outcome::TooLow, 300),
+        );
+        assert_eq!(parse_outcome("<p>That's not the right answer.</p>"), (Outcome::Wrong, 0));
+        assert_eq!(
+            parse_outcome("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p>"),
+            (Outcome::Wait, 83),
+        );
+        assert_eq!(
+            parse_outcome("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"),
+            (Outcome::AlreadySolved, 0),
+        );
+        assert_eq!(parse_outcome("<p>something else</p>"), (Outcome::Unknown, 0));
+    }
+
+    #[test]
+    fn round_trips_submission_lines() {
+        let s = submission(1_700_000_000, 2, "some text", Outcome::TooLow, 60);
+
+        assert_eq!(Submission::from_line(&s.to_line()), Some(s));
+    }
+
+    #[test]
+    fn refuses_repeated_and_out_of_bounds_answers() {
+        let submissions = submissions(vec![
+            submission(0, 1, "100", Outcome::TooHigh, 60),
+            submission(100, 1, "10", Outcome::TooLow, 60),
+            submission(200, 1, "50", Outcome::Wrong, 60),
+            submission(300, 2, "7", Outcome::Correct, 0),
+        ]);
+
+        assert_eq!(submissions.check(1, "50", 1000), Some(Refusal::AlreadyTried { outcome: Outcome::Wrong }));
+        assert_eq!(submissions.check(1, "150", 1000), Some(Refusal::OutOfBounds { bound: 100, outcome: Outcome::TooHigh }));
+        assert_eq!(submissions.check(1, "5", 1000), Some(Refusal::OutOfBounds { bound: 10, outcome: Outcome::TooLow }));
+        assert_eq!(submissions.check(2, "8", 1000), Some(Refusal::AlreadySolved { answer: String::from("7") }));
+        assert_eq!(submissions.check(1, "42", 1000), None);
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let submissions = submissions(vec![submission(1000, 1, "100", Outcome::Wrong, 60)]);
+
+        assert_eq!(submissions.check(1, "42", 1030), Some(Refusal::CoolingDown { seconds_left: 30 }));
+        assert_eq!(submissions.check(1, "42", 1060), None);
+    }
+
+    #[test]
+    fn submits_and_records_answer() {
+        let path = std::env::temp_dir().join(format!("aoc2025-submissions-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut submissions = Submissions::load(&path);
+        let (url, server) = mock_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 60\r\n\r\n<p>That's not the right answer; your answer is too low.</p>\n");
+
+        let submitted = submit_to(|| Ok(url.clone()), identity, &mut submissions, 3, 2, &Answer::Number(1234), 1000).unwrap();
+        let request = server.join().unwrap();
+        let reloaded = Submissions::load(&path);
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(submitted, Submitted::Sent(submission(1000, 2, "1234", Outcome::TooLow, 0)));
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=1234"));
+        assert_eq!(reloaded.entries, submissions.entries);
+
+        // nothing is listening any more, so this only passes without a request
+        assert_eq!(
+            submit_to(|| Ok(url.clone()), identity, &mut submissions, 3, 2, &Answer::Number(1000), 1000).unwrap(),
+            Submitted::Refused(Refusal::OutOfBounds { bound: 1234, outcome: Outcome::TooLow }),
+        );
+    }
+
+    #[test]
+    fn encodes_form_values() {
+        assert_eq!(form_encode("abc-123"), "abc-123");
+        assert_eq!(form_encode("a b&c"), "a%20b%26c");
+    }
+}
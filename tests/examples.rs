@@ -0,0 +1,66 @@
+use aoc2025::answers::{self, Verdict};
+use aoc2025::days;
+use aoc2025::input::{InputKind, InputSelector};
+use aoc2025::runner;
+
+// every example of every registered day, alongside the day
+fn examples() -> Vec<(u8, InputKind)> {
+    days::implemented_days()
+        .flat_map(|day| {
+            InputSelector::AllExamples
+                .resolve(day)
+                .into_iter()
+                .map(move |kind| (day, kind))
+        })
+        .collect()
+}
+
+#[test]
+fn every_day_has_an_example() {
+    let missing: Vec<u8> = days::implemented_days()
+        .filter(|&day| InputSelector::AllExamples.resolve(day).is_empty())
+        .collect();
+
+    assert!(missing.is_empty(), "days without an example input: {:?}", missing);
+}
+
+#[test]
+fn examples_match_expected_answers() {
+    let mut failures = vec![];
+
+    for (day, kind) in examples() {
+        let expected = answers::load_expected_answers(day).unwrap();
+
+        for part in [1, 2] {
+            let case = format!("day {} part {} ({})", day, part, kind.label());
+
+            let result = match runner::run(day, part, &kind) {
+                Ok(result) => result,
+                Err(e) => {
+                    failures.push(format!("{}: {}", case, e));
+                    continue;
+                }
+            };
+
+            match expected.check(&kind, part, &result.answer) {
+                Verdict::Correct => {}
+                Verdict::Mismatch { expected } => {
+                    failures.push(format!("{}: expected {}, got {}", case, expected, result.answer));
+                }
+                Verdict::Unknown => failures.push(format!("{}: no expected answer in answers.txt", case)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{} example(s) failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn both_parts_from_one_parse_match_separate_runs() {
+    for (day, kind) in examples() {
+        let [one, two] = runner::run_both(day, &kind).unwrap();
+
+        assert_eq!(one.answer, runner::run(day, 1, &kind).unwrap().answer, "day {} part 1 ({})", day, kind.label());
+        assert_eq!(two.answer, runner::run(day, 2, &kind).unwrap().answer, "day {} part 2 ({})", day, kind.label());
+    }
+}